@@ -9,11 +9,65 @@
 //! new memory settings.
 //!
 //! The build script also sets the linker flags to tell it which link script to use.
+//!
+//! Finally it generates the sine and arctangent tables of the lookup table
+//! trig backend. The number of segments of each table is set with the
+//! environment variables `TRIG_SINE_SEGMENTS` and `TRIG_ATAN_SEGMENTS`,
+//! 256 by default, and each table takes `4 * (segments + 1)` bytes of flash.
 
 use std::env;
+use std::f64::consts::FRAC_PI_2;
 use std::fs::File;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+const DEFAULT_SEGMENTS: usize = 256;
+
+/// Reads the segment count of a table from the environment variable `name`.
+fn segments(name: &str) -> usize {
+    println!("cargo:rerun-if-env-changed={}", name);
+    let segments = match env::var(name) {
+        Ok(value) => value
+            .parse()
+            .unwrap_or_else(|_| panic!("{} must be an integer, got {:?}", name, value)),
+        Err(_) => DEFAULT_SEGMENTS,
+    };
+    // Quadratic interpolation needs three entries
+    assert!(segments >= 2, "{} must be at least 2", name);
+    return segments;
+}
+
+/// Writes `static name: [f32; segments + 1]` with `f` sampled on `[0, end]`
+/// as bit patterns.
+fn write_table(file: &mut File, name: &str, segments: usize, end: f64, f: fn(f64) -> f64) {
+    writeln!(file, "static {}: [f32; {}] = [", name, segments + 1).unwrap();
+    for i in 0..=segments {
+        let value = f(i as f64 * end / segments as f64) as f32;
+        // Bit patterns are exact and don't resemble well-known constants
+        writeln!(file, "    f32::from_bits({:#010x}),", value.to_bits()).unwrap();
+    }
+    writeln!(file, "];").unwrap();
+}
+
+/// Generates `trig_tables.rs` for the lookup table trig backend.
+fn generate_trig_tables(out: &Path) {
+    let sine_segments = segments("TRIG_SINE_SEGMENTS");
+    let atan_segments = segments("TRIG_ATAN_SEGMENTS");
+
+    let mut file = File::create(out.join("trig_tables.rs")).unwrap();
+    writeln!(
+        file,
+        "/// Segments of the quarter sine wave in `SINE_TABLE`"
+    )
+    .unwrap();
+    writeln!(file, "const SINE_SEGMENTS: usize = {};", sine_segments).unwrap();
+    writeln!(file, "/// Segments of `[0, 1]` in `ATAN_TABLE`").unwrap();
+    writeln!(file, "const ATAN_SEGMENTS: usize = {};", atan_segments).unwrap();
+    writeln!(file, "/// `sin(i * PI / 2 / SINE_SEGMENTS)`").unwrap();
+    write_table(&mut file, "SINE_TABLE", sine_segments, FRAC_PI_2, f64::sin);
+    writeln!(file, "/// `atan(i / ATAN_SEGMENTS)`").unwrap();
+    write_table(&mut file, "ATAN_TABLE", atan_segments, 1.0, f64::atan);
+}
 
 fn main() {
     // Put `memory.x` in our output directory and ensure it's
@@ -31,6 +85,8 @@ fn main() {
     // `memory.x` is changed.
     println!("cargo:rerun-if-changed=memory.x");
 
+    generate_trig_tables(out);
+
     // Specify linker arguments.
 
     // `--nmagic` is required if memory section addresses are not aligned to 0x10000,
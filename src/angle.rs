@@ -0,0 +1,335 @@
+use core::ops;
+use libm::{remainderf, roundf};
+
+use crate::asm_math;
+use crate::trig::{DefaultTrig, TrigBackend};
+
+const TAU: f32 = 2.0 * core::f32::consts::PI;
+/// Scale from Q31 to f32
+const Q31_SCALE: f32 = 1.0 / 2_147_483_648.0;
+
+/// Angle in radians.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Radians(pub f32);
+
+/// Angle in degrees.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Degrees(pub f32);
+
+/// Angle in full turns, i.e. `1.0` is `2 * PI`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Turns(pub f32);
+
+/// 16 bit binary angle, where `2^16` is a full turn. Arithmetic wraps
+/// around for free.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Bam16(pub u16);
+
+/// 32 bit binary angle, where `2^32` is a full turn. Arithmetic wraps
+/// around for free.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Bam32(pub u32);
+
+/// Angle in any unit, accepted by the rotation constructors. A bare `f32`
+/// is in radians.
+///
+/// The trigonometric functions reduce the range in the angle's own unit,
+/// which is exact for degrees and turns and free for binary angles, and
+/// then evaluate `DefaultTrig`. Binary angles use the Q31 CORDIC instead.
+pub trait Angle: Copy {
+    /// The angle in full turns, without range reduction.
+    fn turns(self) -> f32;
+
+    fn radians(self) -> f32 {
+        return self.turns() * TAU;
+    }
+
+    fn degrees(self) -> f32 {
+        return self.turns() * 360.0;
+    }
+
+    /// Half of the angle, for the half-angle formulas of quaternions. Binary
+    /// angles are halved within `[0, 1)` turns, which is off by PI for
+    /// negative angles; `q` and `-q` are the same rotation though.
+    fn half(self) -> Self;
+
+    /// Returns `(sin, cos)` of the angle.
+    fn sin_cos(self) -> (f32, f32) {
+        return DefaultTrig::sin_cos(self.radians());
+    }
+
+    fn sin(self) -> f32 {
+        return self.sin_cos().0;
+    }
+
+    fn cos(self) -> f32 {
+        return self.sin_cos().1;
+    }
+
+    fn tan(self) -> f32 {
+        let (sin, cos) = self.sin_cos();
+        return sin / cos;
+    }
+}
+
+impl Angle for f32 {
+    fn turns(self) -> f32 {
+        return self / TAU;
+    }
+
+    fn half(self) -> Self {
+        return self / 2.0;
+    }
+
+    fn radians(self) -> f32 {
+        return self;
+    }
+}
+
+impl Angle for Radians {
+    fn turns(self) -> f32 {
+        return self.0 / TAU;
+    }
+
+    fn half(self) -> Self {
+        return self / 2.0;
+    }
+
+    fn radians(self) -> f32 {
+        return self.0;
+    }
+}
+
+impl Angle for Degrees {
+    fn turns(self) -> f32 {
+        return self.0 / 360.0;
+    }
+
+    fn half(self) -> Self {
+        return self / 2.0;
+    }
+
+    fn degrees(self) -> f32 {
+        return self.0;
+    }
+
+    fn sin_cos(self) -> (f32, f32) {
+        // Exact remainder in [-180, 180]
+        let reduced = remainderf(self.0, 360.0);
+        return DefaultTrig::sin_cos(reduced.to_radians());
+    }
+}
+
+impl Angle for Turns {
+    fn turns(self) -> f32 {
+        return self.0;
+    }
+
+    fn half(self) -> Self {
+        return self / 2.0;
+    }
+
+    fn sin_cos(self) -> (f32, f32) {
+        // Exact remainder in [-0.5, 0.5]
+        let reduced = self.0 - roundf(self.0);
+        return DefaultTrig::sin_cos(reduced * TAU);
+    }
+}
+
+impl Angle for Bam16 {
+    fn turns(self) -> f32 {
+        return self.0 as f32 / 65_536.0;
+    }
+
+    fn half(self) -> Self {
+        return Bam16(self.0 >> 1);
+    }
+
+    fn sin_cos(self) -> (f32, f32) {
+        return Bam32::from(self).sin_cos();
+    }
+}
+
+impl Angle for Bam32 {
+    fn turns(self) -> f32 {
+        return self.0 as f32 / 4_294_967_296.0;
+    }
+
+    fn half(self) -> Self {
+        return Bam32(self.0 >> 1);
+    }
+
+    fn sin_cos(self) -> (f32, f32) {
+        let (sin, cos) = asm_math::sincos_q31(self.0);
+        return (sin as f32 * Q31_SCALE, cos as f32 * Q31_SCALE);
+    }
+}
+
+impl Radians {
+    pub fn from_turns(turns: f32) -> Self {
+        return Radians(turns * TAU);
+    }
+}
+
+impl Degrees {
+    pub fn from_turns(turns: f32) -> Self {
+        return Degrees(turns * 360.0);
+    }
+}
+
+impl Turns {
+    pub fn from_turns(turns: f32) -> Self {
+        return Turns(turns);
+    }
+}
+
+impl Bam16 {
+    /// Binary angle of `turns`, wrapped around to `[0, 1)` turns and
+    /// rounded to the nearest step. Non-finite angles return 0.
+    pub fn from_turns(turns: f32) -> Self {
+        let steps = roundf((turns - roundf(turns)) * 65_536.0);
+        // Casting to i32 keeps negative steps, which wrap in the u16
+        return Bam16(steps as i32 as u16);
+    }
+}
+
+impl Bam32 {
+    /// Binary angle of `turns`, wrapped around to `[0, 1)` turns and
+    /// rounded to the nearest step representable in f32. Non-finite angles
+    /// return 0.
+    pub fn from_turns(turns: f32) -> Self {
+        let steps = roundf((turns - roundf(turns)) * 4_294_967_296.0);
+        // Casting to i64 keeps negative steps, which wrap in the u32
+        return Bam32(steps as i64 as u32);
+    }
+}
+
+/// Conversions between the units through turns.
+macro_rules! convert {
+    ($from:ident => $($to:ident),+) => {
+        $(
+            impl From<$from> for $to {
+                fn from(angle: $from) -> Self {
+                    return $to::from_turns(angle.turns());
+                }
+            }
+        )+
+    };
+}
+
+convert!(Radians => Degrees, Turns, Bam16, Bam32);
+convert!(Degrees => Radians, Turns, Bam16, Bam32);
+convert!(Turns => Radians, Degrees, Bam16, Bam32);
+convert!(Bam16 => Radians, Degrees, Turns);
+convert!(Bam32 => Radians, Degrees, Turns, Bam16);
+
+impl From<Bam16> for Bam32 {
+    fn from(angle: Bam16) -> Self {
+        return Bam32((angle.0 as u32) << 16);
+    }
+}
+
+/// Arithmetic of the floating point units.
+macro_rules! float_ops {
+    ($($unit:ident),+) => {
+        $(
+            impl ops::Add for $unit {
+                type Output = $unit;
+
+                fn add(self, rhs: $unit) -> Self::Output {
+                    return $unit(self.0 + rhs.0);
+                }
+            }
+
+            impl ops::Sub for $unit {
+                type Output = $unit;
+
+                fn sub(self, rhs: $unit) -> Self::Output {
+                    return $unit(self.0 - rhs.0);
+                }
+            }
+
+            impl ops::Neg for $unit {
+                type Output = $unit;
+
+                fn neg(self) -> Self::Output {
+                    return $unit(-self.0);
+                }
+            }
+
+            impl ops::Mul<f32> for $unit {
+                type Output = $unit;
+
+                fn mul(self, rhs: f32) -> Self::Output {
+                    return $unit(self.0 * rhs);
+                }
+            }
+
+            impl ops::Div<f32> for $unit {
+                type Output = $unit;
+
+                fn div(self, rhs: f32) -> Self::Output {
+                    return $unit(self.0 / rhs);
+                }
+            }
+
+            impl ops::AddAssign for $unit {
+                fn add_assign(&mut self, rhs: $unit) {
+                    self.0 += rhs.0;
+                }
+            }
+
+            impl ops::SubAssign for $unit {
+                fn sub_assign(&mut self, rhs: $unit) {
+                    self.0 -= rhs.0;
+                }
+            }
+        )+
+    };
+}
+
+/// Wrapping arithmetic of the binary angles.
+macro_rules! bam_ops {
+    ($($unit:ident),+) => {
+        $(
+            impl ops::Add for $unit {
+                type Output = $unit;
+
+                fn add(self, rhs: $unit) -> Self::Output {
+                    return $unit(self.0.wrapping_add(rhs.0));
+                }
+            }
+
+            impl ops::Sub for $unit {
+                type Output = $unit;
+
+                fn sub(self, rhs: $unit) -> Self::Output {
+                    return $unit(self.0.wrapping_sub(rhs.0));
+                }
+            }
+
+            impl ops::Neg for $unit {
+                type Output = $unit;
+
+                fn neg(self) -> Self::Output {
+                    return $unit(self.0.wrapping_neg());
+                }
+            }
+
+            impl ops::AddAssign for $unit {
+                fn add_assign(&mut self, rhs: $unit) {
+                    self.0 = self.0.wrapping_add(rhs.0);
+                }
+            }
+
+            impl ops::SubAssign for $unit {
+                fn sub_assign(&mut self, rhs: $unit) {
+                    self.0 = self.0.wrapping_sub(rhs.0);
+                }
+            }
+        )+
+    };
+}
+
+float_ops!(Radians, Degrees, Turns);
+bam_ops!(Bam16, Bam32);
@@ -1,58 +1,773 @@
-#![feature(const_float_bits_conv)]
-#![feature(asm_const)]
-
+#[cfg(all(
+    target_arch = "arm",
+    any(target_abi = "eabihf", target_feature = "vfp2")
+))]
 use core::arch::asm;
-use cortex_m_semihosting::hprintln;
+use libm::roundf;
 
-const ITERATIONS: usize = 10; // Max = 16
+/// Iterations of `sincos`, resolving angles to about `2^-ITERATIONS`.
+pub const ITERATIONS: usize = 10;
+/// Iterations beyond this gain nothing, as `atan(2^-i)` falls below the f32
+/// resolution of the accumulated angle.
+pub const MAX_ITERATIONS: usize = 24;
 const TWO: f32 = 2.0;
 const PI: f32 = f32::from_bits(0x40490FDB);
-const K_N: [f32; 16] = [
-    f32::from_bits(0x3f3504f3),
-    f32::from_bits(0x3f21e89b),
-    f32::from_bits(0x3f1d130e),
-    f32::from_bits(0x3f1bdc8a),
-    f32::from_bits(0x3f1b8ed6),
-    f32::from_bits(0x3f1b7b68),
-    f32::from_bits(0x3f1b768c),
-    f32::from_bits(0x3f1b7555),
-    f32::from_bits(0x3f1b7507),
-    f32::from_bits(0x3f1b74f4),
-    f32::from_bits(0x3f1b74ef),
-    f32::from_bits(0x3f1b74ee),
-    f32::from_bits(0x3f1b74ee),
-    f32::from_bits(0x3f1b74ee),
-    f32::from_bits(0x3f1b74ee),
-    f32::from_bits(0x3f1b74ee),
-];
-const ANGLES: [f32; 16] = [
-    f32::from_bits(0x3f49_0fdb),
-    f32::from_bits(0x3eed_6338),
-    f32::from_bits(0x3e7a_dbb0),
-    f32::from_bits(0x3dfe_add5),
-    f32::from_bits(0x3d7f_aade),
-    f32::from_bits(0x3cff_eaae),
-    f32::from_bits(0x3c7f_faab),
-    f32::from_bits(0x3bff_feab),
-    f32::from_bits(0x3b7f_ffab),
-    f32::from_bits(0x3aff_ffeb),
-    f32::from_bits(0x3a7f_fffb),
-    f32::from_bits(0x39ff_ffff),
-    f32::from_bits(0x3980_0000),
-    f32::from_bits(0x3900_0000),
-    f32::from_bits(0x3880_0000),
-    f32::from_bits(0x3800_0000),
+/// Gain compensation `K_n = 1 / (sqrt(1 + 2^-0) * ... * sqrt(1 + 2^-2(n-1)))`
+/// at index `n - 1`.
+const K_N: [f32; MAX_ITERATIONS] = gain_table();
+/// Rotation angles `atan(2^-i)`
+const ANGLES: [f32; MAX_ITERATIONS] = angle_table();
+/// Hyperbolic iterations, with the shifts 4 and 13 repeated for
+/// convergence.
+const HYPERBOLIC_STEPS: usize = MAX_ITERATIONS + 2;
+/// Shift `i` of each hyperbolic iteration, starting at 1 as `atanh(1)` is
+/// infinite.
+const HYPERBOLIC_SHIFTS: [u32; HYPERBOLIC_STEPS] = hyperbolic_shift_table();
+/// Rotation angles `atanh(2^-i)` of each hyperbolic iteration
+const HYPERBOLIC_ANGLES: [f32; HYPERBOLIC_STEPS] = hyperbolic_angle_table();
+/// Gain compensation `1 / (sqrt(1 - 2^-2i) * ...)` of all hyperbolic
+/// iterations
+const HYPERBOLIC_GAIN: f32 = hyperbolic_gain::<HYPERBOLIC_STEPS>();
+const LN_2: f32 = f32::from_bits(0x3f31_7218);
+/// Iterations of the Q31 CORDIC, after which the shifted operands and the
+/// angles fall below one LSB.
+pub const Q31_ITERATIONS: usize = 30;
+/// Rotation angles `atan(2^-i)` as binary angles, where `2^31` is PI
+const Q31_ANGLES: [i32; Q31_ITERATIONS] = q31_angle_table();
+/// Gain compensation `K_n` at index `n - 1` in Q30
+const Q30_GAINS: [i32; Q31_ITERATIONS] = q30_gain_table();
+/// Scale from radians to signed binary angles
+const BAM_PER_RADIAN: f32 = 2_147_483_648.0 / PI;
+/// `ln(2)` split into a part with trailing zero bits, so `k * LN_2_HI` is
+/// exact, and the remainder
+const LN_2_HI: f32 = f32::from_bits(0x3f31_7200);
+const LN_2_LO: f32 = f32::from_bits(0x35bf_be8e);
+/// `PI` split into a part with trailing zero bits, so `k * PI_HI` is exact
+/// for `|k| < 2^16`, and the remainder
+const PI_HI: f32 = f32::from_bits(0x4049_0000);
+const PI_LO: f32 = f32::from_bits(0x3a7d_aa22);
+
+/// Square root by Newton's method, as `f64::sqrt` isn't available in const
+/// contexts.
+const fn sqrt_f64(value: f64) -> f64 {
+    let mut result = if value > 1.0 { value } else { 1.0 };
+    let mut i = 0;
+    while i < 64 {
+        result = 0.5 * (result + value / result);
+        i += 1;
+    }
+    return result;
+}
+
+/// `atan(2^-i)` by its Taylor series, which converges quickly for
+/// `2^-i <= 0.5`.
+const fn atan_pow2(i: usize) -> f64 {
+    if i == 0 {
+        return core::f64::consts::FRAC_PI_4;
+    }
+    if i >= 64 {
+        return 0.0;
+    }
+
+    let x = 1.0 / (1u64 << i) as f64;
+    let mut term = x;
+    let mut result = 0.0;
+    let mut k = 0;
+    while k < 32 {
+        result += term / (2 * k + 1) as f64;
+        term *= -x * x;
+        k += 1;
+    }
+    return result;
+}
+
+/// Circular rotation angles `atan(2^-i)` for `N` iterations.
+pub const fn angle_table<const N: usize>() -> [f32; N] {
+    let mut table = [0.0; N];
+    let mut i = 0;
+    while i < N {
+        table[i] = atan_pow2(i) as f32;
+        i += 1;
+    }
+    return table;
+}
+
+/// `1 / sqrt((1 + 2^-0) * ... * (1 + 2^-2i))` in f64, the gain compensation
+/// after `i + 1` circular iterations.
+const fn gain_f64(i: usize) -> f64 {
+    let mut product = 1.0;
+    let mut k = 0;
+    // Factors beyond 2^-64 don't change the f64 product
+    while k <= i && k < 32 {
+        product *= 1.0 + 1.0 / (1u64 << (2 * k)) as f64;
+        k += 1;
+    }
+    return 1.0 / sqrt_f64(product);
+}
+
+/// Circular gain compensation `K_n` at index `n - 1` for `N` iterations.
+///
+/// From about 12 iterations on the entries are identical, as the remaining
+/// factors fall below the f32 resolution.
+pub const fn gain_table<const N: usize>() -> [f32; N] {
+    let mut table = [0.0; N];
+    let mut i = 0;
+    while i < N {
+        table[i] = gain_f64(i) as f32;
+        i += 1;
+    }
+    return table;
+}
+
+/// Circular rotation angles `atan(2^-i)` as rounded binary angles, where
+/// `2^31` is PI, for `N` iterations.
+pub const fn q31_angle_table<const N: usize>() -> [i32; N] {
+    let mut table = [0; N];
+    let mut i = 0;
+    while i < N {
+        let bam = atan_pow2(i) / core::f64::consts::PI * 2_147_483_648.0;
+        table[i] = (bam + 0.5) as i32;
+        i += 1;
+    }
+    return table;
+}
+
+/// Circular gain compensation `K_n` at index `n - 1` in rounded Q30 for `N`
+/// iterations.
+pub const fn q30_gain_table<const N: usize>() -> [i32; N] {
+    let mut table = [0; N];
+    let mut i = 0;
+    while i < N {
+        table[i] = (gain_f64(i) * 1_073_741_824.0 + 0.5) as i32;
+        i += 1;
+    }
+    return table;
+}
+
+/// Hyperbolic shifts `i` for `N` iterations, starting at 1 and repeating
+/// `4, 13, 40, ...` for convergence.
+pub const fn hyperbolic_shift_table<const N: usize>() -> [u32; N] {
+    let mut table = [0; N];
+    let mut shift = 1;
+    let mut repeat = 4;
+    let mut i = 0;
+    while i < N {
+        table[i] = shift;
+        if shift == repeat && (i == 0 || table[i - 1] != shift) {
+            repeat = 3 * repeat + 1;
+        } else {
+            shift += 1;
+        }
+        i += 1;
+    }
+    return table;
+}
+
+/// `atanh(2^-i)` by its Taylor series, which converges quickly for
+/// `2^-i <= 0.5`.
+const fn atanh_pow2(i: u32) -> f64 {
+    if i >= 64 {
+        return 0.0;
+    }
+
+    let x = 1.0 / (1u64 << i) as f64;
+    let mut term = x;
+    let mut result = 0.0;
+    let mut k = 0;
+    while k < 32 {
+        result += term / (2 * k + 1) as f64;
+        term *= x * x;
+        k += 1;
+    }
+    return result;
+}
+
+/// Hyperbolic rotation angles `atanh(2^-i)` of each of `N` iterations.
+pub const fn hyperbolic_angle_table<const N: usize>() -> [f32; N] {
+    let shifts = hyperbolic_shift_table::<N>();
+    let mut table = [0.0; N];
+    let mut i = 0;
+    while i < N {
+        table[i] = atanh_pow2(shifts[i]) as f32;
+        i += 1;
+    }
+    return table;
+}
+
+/// `1 / (sqrt(1 - 2^-2i) * ...)` in f64 over the shifts of `N` hyperbolic
+/// iterations.
+const fn hyperbolic_gain_f64<const N: usize>() -> f64 {
+    let shifts = hyperbolic_shift_table::<N>();
+    let mut product = 1.0;
+    let mut i = 0;
+    while i < N {
+        if shifts[i] < 32 {
+            product *= 1.0 - 1.0 / (1u64 << (2 * shifts[i])) as f64;
+        }
+        i += 1;
+    }
+    return 1.0 / sqrt_f64(product);
+}
+
+/// Hyperbolic gain compensation of all `N` iterations.
+pub const fn hyperbolic_gain<const N: usize>() -> f32 {
+    return hyperbolic_gain_f64::<N>() as f32;
+}
+
+// Verification of the generated tables against independent f64 references
+// at compile time
+
+/// `atan(2^-i)` for the first iterations
+const REFERENCE_ANGLES: [f64; 4] = [
+    core::f64::consts::FRAC_PI_4,
+    0.4636476090008061,
+    0.24497866312686414,
+    0.12435499454676144,
 ];
+/// Limit of the circular gain compensation `K_n`
+const REFERENCE_GAIN: f64 = 0.6072529350088812;
+/// Hyperbolic gain compensation of the shifts `1..=24` with 4 and 13
+/// repeated
+const REFERENCE_HYPERBOLIC_GAIN: f64 = 1.2074970677630714;
+/// `atanh(1/2)`
+const REFERENCE_ATANH_HALF: f64 = 0.5493061443340548;
+
+const fn abs_f64(x: f64) -> f64 {
+    return if x < 0.0 { -x } else { x };
+}
+
+/// `tan(x)` for `|x| <= PI/4` from the Taylor series of sine and cosine,
+/// independent of the arctangent series of the tables.
+const fn tan_f64(x: f64) -> f64 {
+    let mut sin_term = x;
+    let mut cos_term = 1.0;
+    let mut sin = 0.0;
+    let mut cos = 0.0;
+    let mut k = 0;
+    while k < 20 {
+        sin += sin_term;
+        cos += cos_term;
+        sin_term *= -x * x / ((2 * k + 2) * (2 * k + 3)) as f64;
+        cos_term *= -x * x / ((2 * k + 1) * (2 * k + 2)) as f64;
+        k += 1;
+    }
+    return sin / cos;
+}
+
+/// `tanh(x)` for `|x| <= 1` from the Taylor series of `e^2x - 1`,
+/// independent of the area hyperbolic tangent series of the tables.
+const fn tanh_f64(x: f64) -> f64 {
+    let mut term = 2.0 * x;
+    let mut expm1 = 0.0;
+    let mut k = 1;
+    while k < 40 {
+        expm1 += term;
+        term *= 2.0 * x / (k + 1) as f64;
+        k += 1;
+    }
+    return expm1 / (expm1 + 2.0);
+}
+
+const _: () = {
+    let mut i = 0;
+    while i < REFERENCE_ANGLES.len() {
+        assert!(
+            abs_f64(atan_pow2(i) - REFERENCE_ANGLES[i]) < 1e-15,
+            "atan(2^-i) differs from the reference"
+        );
+        i += 1;
+    }
+    assert!(
+        abs_f64(atanh_pow2(1) - REFERENCE_ATANH_HALF) < 1e-15,
+        "atanh(2^-i) differs from the reference"
+    );
+
+    // Each angle of the tables rotates by exactly 2^-i
+    let mut i = 0;
+    while i < Q31_ITERATIONS {
+        let x = 1.0 / (1u64 << i) as f64;
+        assert!(
+            abs_f64(tan_f64(atan_pow2(i)) / x - 1.0) < 1e-14,
+            "atan(2^-i) is inconsistent with tan"
+        );
+        i += 1;
+    }
+    let shifts = hyperbolic_shift_table::<HYPERBOLIC_STEPS>();
+    let mut i = 0;
+    while i < HYPERBOLIC_STEPS {
+        let x = 1.0 / (1u64 << shifts[i]) as f64;
+        assert!(
+            abs_f64(tanh_f64(atanh_pow2(shifts[i])) / x - 1.0) < 1e-14,
+            "atanh(2^-i) is inconsistent with tanh"
+        );
+        i += 1;
+    }
+
+    // Gains, and the rounding of the float and fixed-point tables
+    assert!(
+        abs_f64(gain_f64(63) - REFERENCE_GAIN) < 1e-15,
+        "Circular gain differs from the reference"
+    );
+    assert!(
+        abs_f64(hyperbolic_gain_f64::<HYPERBOLIC_STEPS>() - REFERENCE_HYPERBOLIC_GAIN) < 1e-14,
+        "Hyperbolic gain differs from the reference"
+    );
+    let mut i = 0;
+    while i < MAX_ITERATIONS {
+        let angle = atan_pow2(i);
+        assert!(
+            abs_f64(ANGLES[i] as f64 - angle) <= angle * f32::EPSILON as f64 / 2.0,
+            "ANGLES isn't rounded to nearest"
+        );
+        let gain = gain_f64(i);
+        assert!(
+            abs_f64(K_N[i] as f64 - gain) <= gain * f32::EPSILON as f64 / 2.0,
+            "K_N isn't rounded to nearest"
+        );
+        i += 1;
+    }
+    let mut i = 0;
+    while i < Q31_ITERATIONS {
+        let bam = atan_pow2(i) / core::f64::consts::PI * 2_147_483_648.0;
+        assert!(
+            abs_f64(Q31_ANGLES[i] as f64 - bam) <= 0.5,
+            "Q31_ANGLES isn't rounded to nearest"
+        );
+        let gain = gain_f64(i) * 1_073_741_824.0;
+        assert!(
+            abs_f64(Q30_GAINS[i] as f64 - gain) <= 0.5,
+            "Q30_GAINS isn't rounded to nearest"
+        );
+        i += 1;
+    }
+};
+
+/// Computes `(sin(alpha), cos(alpha))` with the CORDIC algorithm in rotation
+/// mode and `ITERATIONS` iterations, see `sincos_iterations`.
+pub fn sincos(alpha: f32) -> (f32, f32) {
+    return sincos_iterations(alpha, ITERATIONS);
+}
+
+/// Computes `(sin(alpha), cos(alpha))` with `N` CORDIC iterations, where
+/// `1 <= N <= MAX_ITERATIONS` is checked at compile time.
+pub fn sincos_n<const N: usize>(alpha: f32) -> (f32, f32) {
+    const {
+        assert!(N >= 1 && N <= MAX_ITERATIONS, "Unsupported iteration count");
+    }
+    return sincos_iterations(alpha, N);
+}
+
+/// Computes `(sin(alpha), cos(alpha))` with the CORDIC algorithm in rotation
+/// mode, accurate to about `2^-iterations`.
+///
+/// The range reduction keeps this accuracy for `|alpha|` up to about `1e4`,
+/// beyond the error grows by about `1e-6` per `1e5`.
+///
+/// Each iteration costs a few cycles, so precision can be traded for
+/// latency per call site. Runs as inline assembly on Armv7-M with FPU, on
+/// the Q31 CORDIC on Arm without FPU and as an equivalent Rust loop on other
+/// architectures. Non-finite angles return NaN.
+pub fn sincos_iterations(alpha: f32, iterations: usize) -> (f32, f32) {
+    assert!(
+        (1..=MAX_ITERATIONS).contains(&iterations),
+        "Iterations must be in 1..={}",
+        MAX_ITERATIONS
+    );
+
+    if !alpha.is_finite() {
+        return (f32::NAN, f32::NAN);
+    }
+
+    // Move alpha into the convergence range [-PI/2, PI/2] of CORDIC by
+    // subtracting k * PI, using sin(a - k * PI) = (-1)^k * sin(a). PI is
+    // subtracted in two parts to keep the remainder exact for large angles.
+    let k = roundf(alpha / PI);
+    let alpha = alpha - k * PI_HI - k * PI_LO;
+    let sign = if k as i64 & 1 == 0 { 1.0 } else { -1.0 };
+
+    let (cos, sin) = cordic_rotate(alpha, iterations);
+    return (sign * sin, sign * cos);
+}
+
+/// Computes `(atan2(y, x), hypot(x, y))` with the CORDIC algorithm in
+/// vectoring mode and `ITERATIONS` iterations, see
+/// `atan2_hypot_iterations`.
+pub fn atan2_hypot(y: f32, x: f32) -> (f32, f32) {
+    return atan2_hypot_iterations(y, x, ITERATIONS);
+}
+
+/// Computes `(atan2(y, x), hypot(x, y))` with `N` CORDIC iterations, where
+/// `1 <= N <= MAX_ITERATIONS` is checked at compile time.
+pub fn atan2_hypot_n<const N: usize>(y: f32, x: f32) -> (f32, f32) {
+    const {
+        assert!(N >= 1 && N <= MAX_ITERATIONS, "Unsupported iteration count");
+    }
+    return atan2_hypot_iterations(y, x, N);
+}
+
+/// Computes `(atan2(y, x), hypot(x, y))` in one pass with the CORDIC
+/// algorithm in vectoring mode, with an angle accurate to about
+/// `2^-iterations` and a relative magnitude error of the same order.
+///
+/// The angle is in `[-PI, PI]` like `atan2f`. Runs as inline assembly on
+/// Armv7-M with FPU, on the Q31 CORDIC on Arm without FPU and as an
+/// equivalent Rust loop on other architectures. All finite inputs keep
+/// their accuracy, except that a magnitude beyond `f32::MAX` becomes
+/// infinite. The zero vector returns 0 for both, non-finite inputs return
+/// NaN.
+pub fn atan2_hypot_iterations(y: f32, x: f32, iterations: usize) -> (f32, f32) {
+    assert!(
+        (1..=MAX_ITERATIONS).contains(&iterations),
+        "Iterations must be in 1..={}",
+        MAX_ITERATIONS
+    );
+
+    if !x.is_finite() || !y.is_finite() {
+        return (f32::NAN, f32::NAN);
+    }
+    if x == 0.0 && y == 0.0 {
+        return (0.0, 0.0);
+    }
+
+    // Scale the larger component into [1, 2) by a power of 2, which is
+    // exact and keeps the CORDIC gain of about 1.65 from overflowing large
+    // vectors
+    let (_, e) = split_exponent(f32::max(x.abs(), y.abs()));
+    let (x, y) = (scale_pow2(x, -e), scale_pow2(y, -e));
+
+    // Move the vector into the convergence range, the right half plane, by
+    // rotating it by PI
+    let (x, y, offset) = if x < 0.0 {
+        (-x, -y, if y < 0.0 { -PI } else { PI })
+    } else {
+        (x, y, 0.0)
+    };
+
+    let (magnitude, angle) = cordic_vector(x, y, iterations);
+    return (angle + offset, scale_pow2(magnitude, e));
+}
+
+/// Below this magnitude `tan`, `asin`, `atan`, `sinh` and `tanh` use their
+/// Taylor series up to the cubic term, which is exact to f32 rounding there, as CORDIC
+/// resolves small results only absolutely.
+const SMALL_ANGLE: f32 = 1.0 / 64.0;
+
+/// Computes `tan(x)` as `sin(x) / cos(x)` from CORDIC rotation with
+/// `MAX_ITERATIONS` iterations.
+///
+/// Absolute error below `1.5e-6` for `|x| <= 1`, growing with `1 / cos(x)^2`
+/// towards the poles. Non-finite inputs return NaN like `tanf`.
+pub fn tan(x: f32) -> f32 {
+    if x.is_nan() {
+        return x;
+    }
+    if x.abs() < SMALL_ANGLE {
+        return x * (1.0 + x * x / 3.0);
+    }
+
+    let (sin, cos) = sincos_iterations(x, MAX_ITERATIONS);
+    return sin / cos;
+}
+
+/// Computes `atan(x)` from CORDIC vectoring of `(1, x)` with
+/// `MAX_ITERATIONS` iterations.
+///
+/// Absolute error below `5e-7` for all finite inputs, as the vectoring
+/// scales large ones down first. Infinite inputs return `±PI/2` like
+/// `atanf`.
+pub fn atan(x: f32) -> f32 {
+    if x.is_nan() {
+        return x;
+    }
+    if x.abs() < SMALL_ANGLE {
+        return x * (1.0 - x * x / 3.0);
+    }
+    if x.is_infinite() {
+        return x.signum() * PI / 2.0;
+    }
+
+    return atan2_hypot_iterations(x, 1.0, MAX_ITERATIONS).0;
+}
+
+/// Computes `asin(x)` as `atan2(x, sqrt(1 - x^2))` from CORDIC vectoring
+/// with `MAX_ITERATIONS` iterations.
+///
+/// Absolute error below `5e-7`. Inputs outside of `[-1, 1]` return NaN
+/// like `asinf`.
+pub fn asin(x: f32) -> f32 {
+    if x.is_nan() {
+        return x;
+    }
+    if x.abs() < SMALL_ANGLE {
+        return x * (1.0 + x * x / 6.0);
+    }
+    if x.abs() > 1.0 {
+        return f32::NAN;
+    }
+
+    // (1 - x) * (1 + x) doesn't cancel close to |x| = 1
+    let cos = sqrt((1.0 - x) * (1.0 + x));
+    return atan2_hypot_iterations(x, cos, MAX_ITERATIONS).0;
+}
+
+/// Computes `acos(x)` as `atan2(sqrt(1 - x^2), x)` from CORDIC vectoring
+/// with `MAX_ITERATIONS` iterations.
+///
+/// Absolute error below `5e-7`. Inputs outside of `[-1, 1]` return NaN
+/// like `acosf`.
+pub fn acos(x: f32) -> f32 {
+    if x.is_nan() || x.abs() > 1.0 {
+        return f32::NAN;
+    }
+    if x == 1.0 {
+        return 0.0;
+    }
+
+    let sin = sqrt((1.0 - x) * (1.0 + x));
+    return atan2_hypot_iterations(sin, x, MAX_ITERATIONS).0;
+}
+
+/// Computes `e^x` with hyperbolic CORDIC after reducing `x` to
+/// `k * ln(2) + r` with `|r| <= ln(2) / 2`.
+///
+/// Relative error below `1.5e-6` in `[-87, 88]`.
+pub fn exp(x: f32) -> f32 {
+    if x.is_nan() {
+        return x;
+    }
+    // Beyond these, the result over- or underflows f32
+    if x > 88.73 {
+        return f32::INFINITY;
+    }
+    if x < -103.98 {
+        return 0.0;
+    }
+
+    return exp_pow2(x, 0);
+}
+
+/// Computes `e^x * 2^n`, applying `2^n` to the exponent of the result so it
+/// doesn't overflow early. `|x|` must be small enough for `x / ln(2)` to fit
+/// in an `i32`.
+fn exp_pow2(x: f32, n: i32) -> f32 {
+    let k = (x / LN_2 + if x < 0.0 { -0.5 } else { 0.5 }) as i32;
+    let r = (x - k as f32 * LN_2_HI) - k as f32 * LN_2_LO;
+    let (cosh, sinh) = cordic_hyperbolic_rotate(r);
+    return scale_pow2(cosh + sinh, k + n);
+}
+
+/// Computes the natural logarithm with hyperbolic CORDIC, using
+/// `ln(m) = 2 * atanh((m - 1) / (m + 1))` for the mantissa `m`.
+///
+/// Absolute error below `4e-7` for `x` in `[0.5, 2]` and relative error
+/// below `4e-7` elsewhere, with `ln(1)` exactly 0. Negative inputs return
+/// NaN and 0 returns negative infinity.
+pub fn ln(x: f32) -> f32 {
+    if x.is_nan() || x < 0.0 {
+        return f32::NAN;
+    }
+    if x == 0.0 {
+        return f32::NEG_INFINITY;
+    }
+    if x == f32::INFINITY {
+        return x;
+    }
+    // The CORDIC residual would leave a tiny non-zero result
+    if x == 1.0 {
+        return 0.0;
+    }
+
+    // Mantissa in [sqrt(1/2), sqrt(2)) keeps ln(m) small
+    let (mut m, mut e) = split_exponent(x);
+    if m > core::f32::consts::SQRT_2 {
+        m /= TWO;
+        e += 1;
+    }
+    let (_, atanh) = cordic_hyperbolic_vector(m + 1.0, m - 1.0);
+    return 2.0 * atanh + e as f32 * LN_2;
+}
+
+/// Computes the square root with hyperbolic CORDIC, using
+/// `sqrt(w) = sqrt((w + 1/4)^2 - (w - 1/4)^2)` for `w` in `[1/4, 1)`.
+///
+/// Relative error below `7e-7`. Negative inputs return NaN.
+pub fn sqrt(x: f32) -> f32 {
+    if x.is_nan() || x < 0.0 {
+        return f32::NAN;
+    }
+    if x == 0.0 || x == f32::INFINITY {
+        return x;
+    }
+
+    // x = w * 4^k with w in [1/4, 1)
+    let (m, e) = split_exponent(x);
+    let (w, k) = if e & 1 == 0 {
+        (m / 4.0, (e + 2) / 2)
+    } else {
+        (m / 2.0, (e + 1) / 2)
+    };
+    let (magnitude, _) = cordic_hyperbolic_vector(w + 0.25, w - 0.25);
+    return scale_pow2(magnitude * HYPERBOLIC_GAIN, k);
+}
+
+/// Computes `(sinh(x), cosh(x))`, directly with hyperbolic CORDIC for
+/// `|x| <= 1` and from `exp` beyond.
+///
+/// Relative error below `1.5e-6`, except for `sinh` with `|x| < 0.1`, where
+/// the absolute error is below `1.5e-7`.
+pub fn sinh_cosh(x: f32) -> (f32, f32) {
+    if x.is_nan() {
+        return (x, x);
+    }
+    if x.abs() <= 1.0 {
+        let (cosh, sinh) = cordic_hyperbolic_rotate(x);
+        if x.abs() < SMALL_ANGLE {
+            return (x * (1.0 + x * x / 6.0), cosh);
+        }
+        return (sinh, cosh);
+    }
+
+    // Beyond this, both overflow f32
+    if x.abs() > 89.5 {
+        return (x.signum() * f32::INFINITY, f32::INFINITY);
+    }
+
+    // e^|x| / 2 stays finite up to where sinh and cosh overflow
+    let half = exp_pow2(x.abs(), -1);
+    let sinh = half - 0.25 / half;
+    let cosh = half + 0.25 / half;
+    return (if x < 0.0 { -sinh } else { sinh }, cosh);
+}
+
+/// Computes `sinh(x)`, see `sinh_cosh`.
+///
+/// Relative error below `1.5e-6` for `|x| >= 0.1` and absolute error below
+/// `1.5e-7` for `|x| < 0.1`.
+pub fn sinh(x: f32) -> f32 {
+    return sinh_cosh(x).0;
+}
+
+/// Computes `cosh(x)`, see `sinh_cosh`.
+///
+/// Relative error below `1.5e-6`.
+pub fn cosh(x: f32) -> f32 {
+    return sinh_cosh(x).1;
+}
+
+/// Computes `tanh(x)` from hyperbolic CORDIC for `|x| <= 1` and from
+/// `1 - 2 / (e^2x + 1)` beyond.
+///
+/// Absolute error below `6e-7`.
+pub fn tanh(x: f32) -> f32 {
+    if x.is_nan() {
+        return x;
+    }
+    if x.abs() < SMALL_ANGLE {
+        return x * (1.0 - x * x / 3.0);
+    }
+    if x.abs() <= 1.0 {
+        let (cosh, sinh) = cordic_hyperbolic_rotate(x);
+        return sinh / cosh;
+    }
+
+    let result = 1.0 - 2.0 / (exp(2.0 * x.abs()) + 1.0);
+    return if x < 0.0 { -result } else { result };
+}
+
+/// Computes `a * b` with linear CORDIC, i.e. by additions and scaling by
+/// powers of 2 only.
+///
+/// Relative error below `1e-6`. Zero and non-finite operands follow
+/// IEEE 754.
+pub fn mul(a: f32, b: f32) -> f32 {
+    if a == 0.0 || b == 0.0 || !a.is_finite() || !b.is_finite() {
+        return a * b;
+    }
+
+    // Mantissas in [1, 2) keep the intermediate product in [1, 4)
+    let (m_a, e_a) = split_exponent(a.abs());
+    let (m_b, e_b) = split_exponent(b.abs());
+    let product = cordic_linear_rotate(m_a, m_b);
+    let product = if (a < 0.0) != (b < 0.0) {
+        -product
+    } else {
+        product
+    };
+    return scale_pow2(product, e_a + e_b);
+}
+
+/// Computes `a / b` with linear CORDIC, i.e. by additions and scaling by
+/// powers of 2 only.
+///
+/// Relative error below `7e-7`. Zero and non-finite operands follow
+/// IEEE 754.
+pub fn div(a: f32, b: f32) -> f32 {
+    if a == 0.0 || b == 0.0 || !a.is_finite() || !b.is_finite() {
+        return a / b;
+    }
 
-pub unsafe fn sincos(alpha: f32) -> (f32, f32) {
-    // Move alpha to correct value range
-    let mut alpha: f32 = alpha % (2.0 * PI);
-    if alpha < -PI {
-        alpha += 2.0 * PI
-    } else if alpha > PI {
-        alpha -= 2.0 * PI
+    let (m_a, e_a) = split_exponent(a.abs());
+    let (m_b, e_b) = split_exponent(b.abs());
+    let quotient = cordic_linear_vector(m_b, m_a);
+    let quotient = if (a < 0.0) != (b < 0.0) {
+        -quotient
+    } else {
+        quotient
+    };
+    return scale_pow2(quotient, e_a - e_b);
+}
+
+/// Splits a positive, finite number into `(m, e)` with `x = m * 2^e` and
+/// `m` in `[1, 2)`.
+fn split_exponent(x: f32) -> (f32, i32) {
+    // Normalize subnormals first
+    let (x, offset) = if x < f32::MIN_POSITIVE {
+        (x * (1u32 << 23) as f32, -23)
+    } else {
+        (x, 0)
+    };
+
+    let bits = x.to_bits();
+    let e = ((bits >> 23) & 0xff) as i32 - 127;
+    let m = f32::from_bits((bits & 0x007f_ffff) | 0x3f80_0000);
+    return (m, e + offset);
+}
+
+/// Multiplies by `2^k` without the intermediate result over- or
+/// underflowing early.
+fn scale_pow2(mut x: f32, mut k: i32) -> f32 {
+    let pow2 = |k: i32| f32::from_bits(((k + 127) as u32) << 23);
+    while k > 127 {
+        x *= pow2(127);
+        k -= 127;
     }
+    while k < -126 {
+        x *= pow2(-126);
+        k += 126;
+    }
+    return x * pow2(k);
+}
 
+/// Rotates `(1, 0)` by `alpha` and returns the scaled result `(x, y)`.
+///
+/// The hard-float ABI implies an FPU, so the assembly is used even if the
+/// build doesn't enable `vfp2` for the whole crate.
+#[cfg(all(
+    target_arch = "arm",
+    any(target_abi = "eabihf", target_feature = "vfp2")
+))]
+fn cordic_rotate(alpha: f32, iterations: usize) -> (f32, f32) {
+    // SAFETY: the FPU is present on hard-float targets and those enabling
+    // vfp2 explicitly
+    return unsafe { cordic_rotate_fpu(alpha, iterations) };
+}
+
+#[cfg(all(
+    target_arch = "arm",
+    any(target_abi = "eabihf", target_feature = "vfp2")
+))]
+#[target_feature(enable = "vfp2")]
+fn cordic_rotate_fpu(alpha: f32, iterations: usize) -> (f32, f32) {
     let mut iter: u32 = 0;
 
     let mut x: f32 = 1.0;
@@ -64,66 +779,621 @@ pub unsafe fn sincos(alpha: f32) -> (f32, f32) {
     let mut p2i: f32 = 1.0;
 
     // CORDIC Armv7-M implementation
-    asm!(
-        "
-    // cordic_iteration:
-    91:
-        // Calculate the next iteration
-        CMP {iter}, #{ITERATIONS}                      // Check if all iterations are done
-        BEQ 95f
-
-        // Get angle from array
-        VLDR.32 {angle}, [{angles_ptr}]             // Load angle from array
-        ADD {angles_ptr}, {angles_ptr}, #4          // Increase pointer by 4B
-
-        // Prepare needed values
-        VMUL.F32 {dx}, {y}, {p2i}
-        VMUL.F32 {dy}, {x}, {p2i}
-
-        // Compare theta & alpha
-        VCMP.F32 {theta}, {alpha}
-        VMRS APSR_nzcv, FPSCR                       // move FP flags to ARM core flags [N,Z,C,V]
-        BLT 93f                                     // Branch if theta >= alpha
-
-    // cordic_rotate_pos:
-    92:
-        VSUB.F32 {theta}, {theta}, {angle}
-        VADD.F32 {x}, {x}, {dx}
-        VSUB.F32 {y}, {y}, {dy}
-
-        B 94f
-
-    // cordic_rotate_neg:
-    93:
-        VADD.F32 {theta}, {theta}, {angle}
-        VSUB.F32 {x}, {x}, {dx}
-        VADD.F32 {y}, {y}, {dy}
-
-    // cordic_rotate_done:
-    94:
-        VDIV.F32 {p2i}, {p2i}, {TWO}
-        ADD {iter}, {iter}, #1                     // Increment iteration counter
-
-        B 91b
-
-    // cordic_done:
-    95:
-        VMUL.F32 {x}, {x}, {k}
-        VMUL.F32 {y}, {y}, {k}",
-    ITERATIONS = const ITERATIONS,
-    TWO = in(sreg) TWO,
-    alpha = in(sreg) alpha,
-    k = in(sreg) K_N[ITERATIONS - 1],
-    angles_ptr = in(reg) &ANGLES as *const f32,
-    iter = inout(reg) iter,
-    x = inout(sreg) x,
-    y = inout(sreg) y,
-    angle = inout(sreg) angle,
-    theta = inout(sreg) theta,
-    dx = inout(sreg) dx,
-    dy = inout(sreg) dy,
-    p2i = inout(sreg) p2i,
+    // SAFETY: Only registers are touched besides reading `iterations`
+    // entries of ANGLES, which has MAX_ITERATIONS entries.
+    unsafe {
+        asm!(
+            "
+        // cordic_iteration:
+        91:
+            // Calculate the next iteration
+            CMP {iter}, {iterations}                       // Check if all iterations are done
+            BEQ 95f
+
+            // Get angle from array
+            VLDR.32 {angle}, [{angles_ptr}]             // Load angle from array
+            ADD {angles_ptr}, {angles_ptr}, #4          // Increase pointer by 4B
+
+            // Prepare needed values
+            VMUL.F32 {dx}, {y}, {p2i}
+            VMUL.F32 {dy}, {x}, {p2i}
+
+            // Compare theta & alpha
+            VCMP.F32 {theta}, {alpha}
+            VMRS APSR_nzcv, FPSCR                       // move FP flags to ARM core flags [N,Z,C,V]
+            BLT 93f                                     // Branch if theta < alpha
+
+        // cordic_rotate_pos:
+        92:
+            VSUB.F32 {theta}, {theta}, {angle}
+            VADD.F32 {x}, {x}, {dx}
+            VSUB.F32 {y}, {y}, {dy}
+
+            B 94f
+
+        // cordic_rotate_neg:
+        93:
+            VADD.F32 {theta}, {theta}, {angle}
+            VSUB.F32 {x}, {x}, {dx}
+            VADD.F32 {y}, {y}, {dy}
+
+        // cordic_rotate_done:
+        94:
+            VDIV.F32 {p2i}, {p2i}, {TWO}
+            ADD {iter}, {iter}, #1                     // Increment iteration counter
+
+            B 91b
+
+        // cordic_done:
+        95:
+            VMUL.F32 {x}, {x}, {k}
+            VMUL.F32 {y}, {y}, {k}",
+        iterations = in(reg) iterations as u32,
+        TWO = in(sreg) TWO,
+        alpha = in(sreg) alpha,
+        k = in(sreg) K_N[iterations - 1],
+        // The pointer is advanced by the loop
+        angles_ptr = inout(reg) &ANGLES as *const f32 => _,
+        iter = inout(reg) iter,
+        x = inout(sreg) x,
+        y = inout(sreg) y,
+        angle = inout(sreg) angle,
+        theta = inout(sreg) theta,
+        dx = inout(sreg) dx,
+        dy = inout(sreg) dy,
+        p2i = inout(sreg) p2i,
+        );
+    }
+
+    return (x, y);
+}
+
+/// Portable version of the assembly above, performing the same floating
+/// point operations in the same order.
+#[cfg(not(target_arch = "arm"))]
+fn cordic_rotate(alpha: f32, iterations: usize) -> (f32, f32) {
+    let mut x: f32 = 1.0;
+    let mut y: f32 = 0.0;
+    let mut theta: f32 = 0.0;
+    let mut p2i: f32 = 1.0;
+
+    for angle in ANGLES.iter().take(iterations) {
+        let dx = y * p2i;
+        let dy = x * p2i;
+
+        if theta < alpha {
+            theta += angle;
+            x -= dx;
+            y += dy;
+        } else {
+            theta -= angle;
+            x += dx;
+            y -= dy;
+        }
+
+        p2i /= TWO;
+    }
+
+    let k = K_N[iterations - 1];
+    return (x * k, y * k);
+}
+
+/// Version for Arm targets without FPU, running the Q31 CORDIC instead of
+/// emulating the floating point operations.
+#[cfg(all(
+    target_arch = "arm",
+    not(any(target_abi = "eabihf", target_feature = "vfp2"))
+))]
+fn cordic_rotate(alpha: f32, iterations: usize) -> (f32, f32) {
+    let (x, y) = cordic_rotate_q30((alpha * BAM_PER_RADIAN) as i32, iterations);
+    return (x as f32 / Q30_ONE, y as f32 / Q30_ONE);
+}
+
+/// Rotates `(x, y)` onto the positive x-axis and returns the scaled
+/// magnitude and the accumulated angle.
+#[cfg(all(
+    target_arch = "arm",
+    any(target_abi = "eabihf", target_feature = "vfp2")
+))]
+fn cordic_vector(x: f32, y: f32, iterations: usize) -> (f32, f32) {
+    // SAFETY: the FPU is present on hard-float targets and those enabling
+    // vfp2 explicitly
+    return unsafe { cordic_vector_fpu(x, y, iterations) };
+}
+
+#[cfg(all(
+    target_arch = "arm",
+    any(target_abi = "eabihf", target_feature = "vfp2")
+))]
+#[target_feature(enable = "vfp2")]
+fn cordic_vector_fpu(x: f32, y: f32, iterations: usize) -> (f32, f32) {
+    let mut iter: u32 = 0;
+
+    let mut x: f32 = x;
+    let mut y: f32 = y;
+    let mut angle: f32 = 0.0;
+    let mut theta: f32 = 0.0;
+    let mut dx: f32 = 0.0;
+    let mut dy: f32 = 0.0;
+    let mut p2i: f32 = 1.0;
+
+    // CORDIC Armv7-M implementation
+    // SAFETY: Only registers are touched besides reading `iterations`
+    // entries of ANGLES, which has MAX_ITERATIONS entries.
+    unsafe {
+        asm!(
+            "
+        // cordic_iteration:
+        91:
+            // Calculate the next iteration
+            CMP {iter}, {iterations}                       // Check if all iterations are done
+            BEQ 95f
+
+            // Get angle from array
+            VLDR.32 {angle}, [{angles_ptr}]             // Load angle from array
+            ADD {angles_ptr}, {angles_ptr}, #4          // Increase pointer by 4B
+
+            // Prepare needed values
+            VMUL.F32 {dx}, {y}, {p2i}
+            VMUL.F32 {dy}, {x}, {p2i}
+
+            // Compare y & 0
+            VCMP.F32 {y}, #0.0
+            VMRS APSR_nzcv, FPSCR                       // move FP flags to ARM core flags [N,Z,C,V]
+            BLT 93f                                     // Branch if y < 0
+
+        // cordic_rotate_cw:
+        92:
+            VADD.F32 {theta}, {theta}, {angle}
+            VADD.F32 {x}, {x}, {dx}
+            VSUB.F32 {y}, {y}, {dy}
+
+            B 94f
+
+        // cordic_rotate_ccw:
+        93:
+            VSUB.F32 {theta}, {theta}, {angle}
+            VSUB.F32 {x}, {x}, {dx}
+            VADD.F32 {y}, {y}, {dy}
+
+        // cordic_rotate_done:
+        94:
+            VDIV.F32 {p2i}, {p2i}, {TWO}
+            ADD {iter}, {iter}, #1                     // Increment iteration counter
+
+            B 91b
+
+        // cordic_done:
+        95:
+            VMUL.F32 {x}, {x}, {k}",
+        iterations = in(reg) iterations as u32,
+        TWO = in(sreg) TWO,
+        k = in(sreg) K_N[iterations - 1],
+        // The pointer is advanced by the loop
+        angles_ptr = inout(reg) &ANGLES as *const f32 => _,
+        iter = inout(reg) iter,
+        x = inout(sreg) x,
+        y = inout(sreg) y,
+        angle = inout(sreg) angle,
+        theta = inout(sreg) theta,
+        dx = inout(sreg) dx,
+        dy = inout(sreg) dy,
+        p2i = inout(sreg) p2i,
+        );
+    }
+
+    return (x, theta);
+}
+
+/// Portable version of the assembly above, performing the same floating
+/// point operations in the same order.
+#[cfg(not(target_arch = "arm"))]
+fn cordic_vector(x: f32, y: f32, iterations: usize) -> (f32, f32) {
+    let mut x: f32 = x;
+    let mut y: f32 = y;
+    let mut theta: f32 = 0.0;
+    let mut p2i: f32 = 1.0;
+
+    for angle in ANGLES.iter().take(iterations) {
+        let dx = y * p2i;
+        let dy = x * p2i;
+
+        if y < 0.0 {
+            theta -= angle;
+            x -= dx;
+            y += dy;
+        } else {
+            theta += angle;
+            x += dx;
+            y -= dy;
+        }
+
+        p2i /= TWO;
+    }
+
+    return (x * K_N[iterations - 1], theta);
+}
+
+/// Version for Arm targets without FPU, running the Q31 CORDIC instead of
+/// emulating the floating point operations.
+#[cfg(all(
+    target_arch = "arm",
+    not(any(target_abi = "eabihf", target_feature = "vfp2"))
+))]
+fn cordic_vector(x: f32, y: f32, iterations: usize) -> (f32, f32) {
+    // Larger component in [1/2, 1) in Q29, scaled by a power of 2 so
+    // subnormal and large inputs neither under- nor overflow
+    let (_, e) = split_exponent(f32::max(x.abs(), y.abs()));
+    let (x, y) = (scale_pow2(x, 28 - e), scale_pow2(y, 28 - e));
+    let (magnitude, angle) = cordic_vector_q29(x as i32, y as i32, iterations);
+    return (
+        scale_pow2(magnitude as f32 * K_N[iterations - 1], e - 28),
+        angle as f32 / BAM_PER_RADIAN,
+    );
+}
+
+/// Rotates `(1/K, 0)` hyperbolically by `z`, with `|z| <= 1.118`, and
+/// returns `(cosh(z), sinh(z))`.
+fn cordic_hyperbolic_rotate(mut z: f32) -> (f32, f32) {
+    let mut x: f32 = HYPERBOLIC_GAIN;
+    let mut y: f32 = 0.0;
+
+    for i in 0..HYPERBOLIC_STEPS {
+        let p2i = 1.0 / (1u32 << HYPERBOLIC_SHIFTS[i]) as f32;
+        let dx = y * p2i;
+        let dy = x * p2i;
+
+        if z < 0.0 {
+            z += HYPERBOLIC_ANGLES[i];
+            x -= dx;
+            y -= dy;
+        } else {
+            z -= HYPERBOLIC_ANGLES[i];
+            x += dx;
+            y += dy;
+        }
+    }
+
+    return (x, y);
+}
+
+/// Rotates `(x, y)` hyperbolically onto the x-axis, with `x > 0` and
+/// `|atanh(y / x)| <= 1.118`, and returns the magnitude
+/// `sqrt(x^2 - y^2) / HYPERBOLIC_GAIN` and the angle `atanh(y / x)`.
+fn cordic_hyperbolic_vector(mut x: f32, mut y: f32) -> (f32, f32) {
+    let mut z: f32 = 0.0;
+
+    for i in 0..HYPERBOLIC_STEPS {
+        let p2i = 1.0 / (1u32 << HYPERBOLIC_SHIFTS[i]) as f32;
+        let dx = y * p2i;
+        let dy = x * p2i;
+
+        if y < 0.0 {
+            z -= HYPERBOLIC_ANGLES[i];
+            x += dx;
+            y += dy;
+        } else {
+            z += HYPERBOLIC_ANGLES[i];
+            x -= dx;
+            y -= dy;
+        }
+    }
+
+    return (x, z);
+}
+
+/// Accumulates `x * z` for `|z| < 2` in linear rotation mode.
+fn cordic_linear_rotate(x: f32, mut z: f32) -> f32 {
+    let mut y: f32 = 0.0;
+    let mut p2i: f32 = 1.0;
+
+    for _ in 0..MAX_ITERATIONS {
+        if z < 0.0 {
+            z += p2i;
+            y -= x * p2i;
+        } else {
+            z -= p2i;
+            y += x * p2i;
+        }
+        p2i /= TWO;
+    }
+
+    return y;
+}
+
+/// Accumulates `y / x` for `x > 0` and `|y / x| < 2` in linear vectoring
+/// mode.
+fn cordic_linear_vector(x: f32, mut y: f32) -> f32 {
+    let mut z: f32 = 0.0;
+    let mut p2i: f32 = 1.0;
+
+    for _ in 0..MAX_ITERATIONS {
+        if y < 0.0 {
+            z -= p2i;
+            y += x * p2i;
+        } else {
+            z += p2i;
+            y -= x * p2i;
+        }
+        p2i /= TWO;
+    }
+
+    return z;
+}
+
+// Fixed-point Q31
+
+const Q30_ONE: f32 = (1u32 << 30) as f32;
+
+/// Computes `(sin, cos)` in Q31 of a binary angle, where `2^32` is a full
+/// turn, with `Q31_ITERATIONS` iterations, see `sincos_q31_iterations`.
+pub fn sincos_q31(angle: u32) -> (i32, i32) {
+    return sincos_q31_iterations(angle, Q31_ITERATIONS);
+}
+
+/// Computes `(sin, cos)` in Q31 of a binary angle, where `2^32` is a full
+/// turn, with an integer-only CORDIC of shifts and additions.
+///
+/// The result is accurate to about `2^-iterations`, down to about `2^-26`
+/// with all iterations due to the truncating shifts. Exact values keep this
+/// residual too, e.g. `sincos_q31(0)` has a sine of 12 LSB instead of 0.
+/// Binary angles wrap around for free, so no range reduction is needed
+/// beyond the quadrant.
+pub fn sincos_q31_iterations(angle: u32, iterations: usize) -> (i32, i32) {
+    assert!(
+        (1..=Q31_ITERATIONS).contains(&iterations),
+        "Iterations must be in 1..={}",
+        Q31_ITERATIONS
     );
 
+    // Signed angle in [-PI, PI), moved into [-PI/2, PI/2] by adding PI
+    let mut z = angle as i32;
+    let negate = !(-(1 << 30)..=(1 << 30)).contains(&z);
+    if negate {
+        z = z.wrapping_add(i32::MIN);
+    }
+
+    let (cos, sin) = cordic_rotate_q30(z, iterations);
+    // Q30 to Q31, where 1 saturates to the largest Q31 value
+    let (sin, cos) = (sin.saturating_add(sin), cos.saturating_add(cos));
+    if negate {
+        return (sin.saturating_neg(), cos.saturating_neg());
+    }
+    return (sin, cos);
+}
+
+/// Computes `(atan2(y, x), hypot(x, y))` of Q31 coordinates with
+/// `Q31_ITERATIONS` iterations, see `atan2_hypot_q31_iterations`.
+pub fn atan2_hypot_q31(y: i32, x: i32) -> (u32, u32) {
+    return atan2_hypot_q31_iterations(y, x, Q31_ITERATIONS);
+}
+
+/// Computes `(atan2(y, x), hypot(x, y))` of Q31 coordinates with an
+/// integer-only CORDIC of shifts and additions.
+///
+/// The angle is a binary angle, where `2^32` is a full turn; cast it to
+/// `i32` for the range `[-PI, PI)`. The magnitude is an unsigned Q31 value,
+/// as it reaches `sqrt(2)`. Both are accurate to about `2^-iterations`,
+/// down to about `1e-7` with all iterations as the inputs are normalized
+/// to Q29 for headroom. The zero vector returns 0 for both.
+pub fn atan2_hypot_q31_iterations(y: i32, x: i32, iterations: usize) -> (u32, u32) {
+    assert!(
+        (1..=Q31_ITERATIONS).contains(&iterations),
+        "Iterations must be in 1..={}",
+        Q31_ITERATIONS
+    );
+
+    if x == 0 && y == 0 {
+        return (0, 0);
+    }
+
+    // Normalize the larger component to [2^28, 2^29), i.e. Q29, which
+    // leaves room for the gain of sqrt(2) * 1.65 and keeps small vectors
+    // from collapsing to zero. The rotation by PI into the right half plane
+    // can't overflow then.
+    let largest = u32::max(x.unsigned_abs(), y.unsigned_abs());
+    let shift = largest.leading_zeros() as i32 - 3;
+    let (x, y) = if shift >= 0 {
+        (x << shift, y << shift)
+    } else {
+        (x >> -shift, y >> -shift)
+    };
+    let (x, y, offset) = if x < 0 { (-x, -y, 1 << 31) } else { (x, y, 0) };
+
+    let (magnitude, angle) = cordic_vector_q29(x, y, iterations);
+    // Times the Q30 gain and undoing the normalization gives Q31
+    let magnitude = (magnitude as i64 * Q30_GAINS[iterations - 1] as i64) >> (30 + shift);
+    return ((angle as u32).wrapping_add(offset), magnitude as u32);
+}
+
+/// Rotates `(K, 0)` in Q30 by the binary angle `z` with `|z| <= 2^30` and
+/// returns `(cos(z), sin(z))` in Q30.
+fn cordic_rotate_q30(mut z: i32, iterations: usize) -> (i32, i32) {
+    let mut x: i32 = Q30_GAINS[iterations - 1];
+    let mut y: i32 = 0;
+
+    for (i, angle) in Q31_ANGLES.iter().take(iterations).enumerate() {
+        let dx = y >> i;
+        let dy = x >> i;
+
+        if z < 0 {
+            z += angle;
+            x += dx;
+            y -= dy;
+        } else {
+            z -= angle;
+            x -= dx;
+            y += dy;
+        }
+    }
+
     return (x, y);
 }
+
+/// Rotates the Q29 vector `(x, y)` with `x >= 0` onto the positive x-axis
+/// and returns the unscaled magnitude and the binary angle.
+fn cordic_vector_q29(mut x: i32, mut y: i32, iterations: usize) -> (i32, i32) {
+    let mut z: i32 = 0;
+
+    for (i, angle) in Q31_ANGLES.iter().take(iterations).enumerate() {
+        let dx = y >> i;
+        let dy = x >> i;
+
+        if y < 0 {
+            z -= angle;
+            x -= dx;
+            y += dy;
+        } else {
+            z += angle;
+            x += dx;
+            y -= dy;
+        }
+    }
+
+    return (x, z);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_relative(actual: f32, expected: f32, tolerance: f32) {
+        let error = ((actual - expected) / expected).abs();
+        assert!(
+            error < tolerance,
+            "{} differs from {} by {:e}",
+            actual,
+            expected,
+            error
+        );
+    }
+
+    /// Largest error of `f` against the f64 `reference` at points spaced
+    /// evenly in `[lo, hi]`, relative to the reference or absolute.
+    fn sweep(
+        f: impl Fn(f32) -> f32,
+        reference: impl Fn(f64) -> f64,
+        lo: f32,
+        hi: f32,
+        relative: bool,
+    ) -> f64 {
+        const STEPS: usize = 100_000;
+        let mut worst: f64 = 0.0;
+        for i in 0..=STEPS {
+            let x = lo + (hi - lo) * (i as f32 / STEPS as f32);
+            let expected = reference(x as f64);
+            let mut error = (f(x) as f64 - expected).abs();
+            if relative {
+                error /= expected.abs();
+            }
+            worst = f64::max(worst, error);
+        }
+        return worst;
+    }
+
+    #[test]
+    fn exponential_and_logarithm_error_bounds() {
+        assert!(sweep(exp, libm::exp, -87.0, 88.0, true) < 1.5e-6);
+        assert!(sweep(ln, libm::log, 0.5, 2.0, false) < 4e-7);
+        assert!(sweep(ln, libm::log, 1e-30, 0.5, true) < 4e-7);
+        assert!(sweep(ln, libm::log, 2.0, 1e6, true) < 4e-7);
+        assert!(sweep(sqrt, libm::sqrt, 0.25, 4.0, true) < 7e-7);
+    }
+
+    #[test]
+    fn hyperbolic_error_bounds() {
+        assert!(sweep(sinh, libm::sinh, -0.1, 0.1, false) < 1.5e-7);
+        assert!(sweep(sinh, libm::sinh, 0.1, 89.4, true) < 1.5e-6);
+        assert!(sweep(sinh, libm::sinh, -89.4, -0.1, true) < 1.5e-6);
+        assert!(sweep(cosh, libm::cosh, -89.4, 89.4, true) < 1.5e-6);
+        assert!(sweep(tanh, libm::tanh, -20.0, 20.0, false) < 6e-7);
+    }
+
+    #[test]
+    fn linear_error_bounds() {
+        for i in 0..10 {
+            let b = 1.0 + i as f32 / 10.0;
+            assert!(sweep(|a| mul(a, b), |a| a * b as f64, 1.0, 2.0, true) < 1e-6);
+            assert!(sweep(|a| div(a, b), |a| a / b as f64, 1.0, 2.0, true) < 7e-7);
+        }
+    }
+
+    #[test]
+    fn mul_large_and_small_operands() {
+        assert_relative(mul(3e38, 1e-10), 3e28, 1e-6);
+        assert_relative(mul(1e-10, 3e38), 3e28, 1e-6);
+        assert_relative(mul(-3e38, 1e-10), -3e28, 1e-6);
+        assert_relative(mul(1e-30, -2e35), -2e5, 1e-6);
+        assert_eq!(mul(3e38, 10.0), f32::INFINITY);
+    }
+
+    #[test]
+    fn sinh_cosh_close_to_overflow() {
+        let (sinh, cosh) = sinh_cosh(89.0);
+        assert_relative(sinh, 2.244_806e38, 1e-6);
+        assert_relative(cosh, 2.244_806e38, 1e-6);
+        assert_relative(sinh_cosh(-89.0).0, -2.244_806e38, 1e-6);
+        assert_eq!(sinh_cosh(89.5), (f32::INFINITY, f32::INFINITY));
+        assert_eq!(sinh_cosh(-1e10), (f32::NEG_INFINITY, f32::INFINITY));
+    }
+
+    #[test]
+    fn ln_of_one_is_exact() {
+        assert_eq!(ln(1.0), 0.0);
+    }
+
+    #[test]
+    fn sincos_of_large_angles() {
+        let sin = |x| sincos_iterations(x, MAX_ITERATIONS).0;
+        let cos = |x| sincos_iterations(x, MAX_ITERATIONS).1;
+        assert!(sweep(sin, libm::sin, -1e4, 1e4, false) < 6e-7);
+        assert!(sweep(cos, libm::cos, -1e4, 1e4, false) < 6e-7);
+        assert!(sweep(sin, libm::sin, 9e4, 1e5, false) < 2e-6);
+    }
+
+    #[test]
+    fn inverse_trigonometric_error_bounds() {
+        assert!(sweep(tan, libm::tan, -1.0, 1.0, false) < 1.5e-6);
+        assert!(sweep(atan, libm::atan, -10.0, 10.0, false) < 5e-7);
+        assert!(sweep(asin, libm::asin, -1.0, 1.0, false) < 5e-7);
+        assert!(sweep(acos, libm::acos, -1.0, 1.0, false) < 5e-7);
+    }
+
+    #[test]
+    fn atan_of_large_inputs() {
+        for &x in &[1e10, 3e38, f32::MAX] {
+            assert!((atan(x) - PI / 2.0).abs() < 5e-7);
+            assert!((atan(-x) + PI / 2.0).abs() < 5e-7);
+        }
+    }
+
+    #[test]
+    fn atan2_hypot_extreme_magnitudes() {
+        let atan2_hypot = |y, x| atan2_hypot_iterations(y, x, MAX_ITERATIONS);
+        for &scale in &[2e38, 1.0, 1e-30, 1e-44] {
+            let (angle, magnitude) = atan2_hypot(scale, scale);
+            assert!((angle - PI / 4.0).abs() < 5e-7);
+            assert_relative(magnitude, scale * core::f32::consts::SQRT_2, 1e-6);
+        }
+
+        let (angle, magnitude) = atan2_hypot(1e38, -2e38);
+        assert!((angle - libm::atan2f(1e38, -2e38)).abs() < 5e-7);
+        assert_relative(magnitude, 2.236_068e38, 1e-6);
+        // Only the magnitude overflows
+        let (angle, magnitude) = atan2_hypot(-3e38, -3e38);
+        assert!((angle + 3.0 * PI / 4.0).abs() < 5e-7);
+        assert_eq!(magnitude, f32::INFINITY);
+    }
+
+    #[test]
+    fn atan2_hypot_q31_small_vectors() {
+        // Binary angles of PI/4, 3PI/4 and -PI/2
+        let eighth = 1u32 << 29;
+        assert!(atan2_hypot_q31(1, 1).0.abs_diff(eighth) < 1000);
+        assert!(atan2_hypot_q31(3, -3).0.abs_diff(3 * eighth) < 1000);
+        assert!(atan2_hypot_q31(-2, 0).0.abs_diff(6 * eighth) < 1000);
+        let (angle, magnitude) = atan2_hypot_q31(0, 1);
+        assert!((angle as i32).abs() < 1000 && magnitude == 1);
+        assert_eq!(atan2_hypot_q31(0, 0), (0, 0));
+    }
+}
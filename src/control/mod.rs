@@ -0,0 +1,5 @@
+mod riccati;
+mod state_space;
+
+pub use riccati::{care, dare, dlqr, lqr};
+pub use state_space::StateSpace;
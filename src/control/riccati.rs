@@ -0,0 +1,189 @@
+use libm::sqrtf;
+
+use crate::matrix::{ConvergenceReport, IterationLimits, Matrix};
+
+fn assert_system(a: &Matrix, b: &Matrix, q: &Matrix, r: &Matrix) {
+    let n = a.m;
+    assert!(a.is_quadratic(), "A must be quadratic");
+    assert!(b.m == n, "B must have {} rows", n);
+    assert!(q.shape() == (n, n), "Q must be of shape {}x{}", n, n);
+    assert!(
+        r.shape() == (b.n, b.n),
+        "R must be of shape {}x{}",
+        b.n,
+        b.n
+    );
+}
+
+/// Averages `P` with its transpose to remove rounding asymmetry.
+fn symmetrize(p: &mut Matrix) {
+    for i in 0..p.m {
+        for j in 0..i {
+            let mean = (p[(i, j)] + p[(j, i)]) / 2.0;
+            p[(i, j)] = mean;
+            p[(j, i)] = mean;
+        }
+    }
+}
+
+/// Solves the discrete algebraic Riccati equation
+///
+/// `P = A^T * P * A - A^T * P * B * (R + B^T * P * B)^-1 * B^T * P * A + Q`
+///
+/// by fixed-point iteration starting at `P = Q`. The residual of the report
+/// is the relative change of `P` in the last iteration. Returns `None` if
+/// `R + B^T * P * B` becomes singular.
+pub fn dare(
+    a: &Matrix,
+    b: &Matrix,
+    q: &Matrix,
+    r: &Matrix,
+    limits: IterationLimits,
+) -> Option<(Matrix, ConvergenceReport)> {
+    assert_system(a, b, q, r);
+
+    let a_t = a.T();
+    let b_t = b.T();
+    let mut p = q.clone();
+    let mut iterations = 0;
+    let mut residual = f32::INFINITY;
+
+    while residual > limits.tolerance && iterations < limits.max_iterations {
+        let pa = &p * a;
+        let pb = &p * b;
+        let gain_inverse = (r + &(&b_t * &pb)).inverse()?;
+
+        let mut next = &(&a_t * &pa) - &(&(&(&a_t * &pb) * &gain_inverse) * &(&b_t * &pa));
+        next += q;
+        symmetrize(&mut next);
+
+        residual = (&next - &p).norm_frobenius() / f32::max(next.norm_frobenius(), f32::EPSILON);
+        p = next;
+        iterations += 1;
+    }
+
+    let report = ConvergenceReport {
+        iterations,
+        residual,
+        converged: residual <= limits.tolerance,
+    };
+    return Some((p, report));
+}
+
+/// Designs the discrete LQR state feedback `u = -K * x` minimizing
+/// `sum(x^T * Q * x + u^T * R * u)` and returns `(K, P, report)`, where `P`
+/// solves the discrete algebraic Riccati equation, or `None` if
+/// `R + B^T * P * B` is singular.
+pub fn dlqr(
+    a: &Matrix,
+    b: &Matrix,
+    q: &Matrix,
+    r: &Matrix,
+    limits: IterationLimits,
+) -> Option<(Matrix, Matrix, ConvergenceReport)> {
+    let (p, report) = dare(a, b, q, r, limits)?;
+
+    // K = (R + B^T * P * B)^-1 * B^T * P * A
+    let b_t_p = &b.T() * &p;
+    let gain_inverse = (r + &(&b_t_p * b)).inverse()?;
+    let k = &(&gain_inverse * &b_t_p) * a;
+
+    return Some((k, p, report));
+}
+
+/// Solves the continuous algebraic Riccati equation
+///
+/// `A^T * P + P * A - P * B * R^-1 * B^T * P + Q = 0`
+///
+/// with the matrix sign function of the Hamiltonian
+/// `H = [A  -B * R^-1 * B^T; -Q  -A^T]`, computed by the scaled Newton
+/// iteration `Z = (c * Z + Z^-1 / c) / 2`. The residual of the report is the
+/// relative change of `Z` in the last iteration.
+///
+/// Returns `None` if `R` is singular or if there is no stabilizing solution,
+/// e.g. if the Hamiltonian has eigenvalues on the imaginary axis as for
+/// uncontrollable systems.
+pub fn care(
+    a: &Matrix,
+    b: &Matrix,
+    q: &Matrix,
+    r: &Matrix,
+    limits: IterationLimits,
+) -> Option<(Matrix, ConvergenceReport)> {
+    assert_system(a, b, q, r);
+
+    let n = a.m;
+    let r_inverse = r.inverse()?;
+    let g = &(b * &r_inverse) * &b.T();
+
+    // Hamiltonian matrix
+    let mut z = Matrix::zeros((2 * n, 2 * n));
+    for i in 0..n {
+        for j in 0..n {
+            z[(i, j)] = a[(i, j)];
+            z[(i, n + j)] = -g[(i, j)];
+            z[(n + i, j)] = -q[(i, j)];
+            z[(n + i, n + j)] = -a[(j, i)];
+        }
+    }
+
+    let mut iterations = 0;
+    let mut residual = f32::INFINITY;
+
+    while residual > limits.tolerance && iterations < limits.max_iterations {
+        // Singular for eigenvalues on the imaginary axis
+        let z_inverse = z.inverse()?;
+
+        // Scaling speeds up the initial convergence
+        let c = sqrtf(z_inverse.norm_frobenius() / z.norm_frobenius());
+        let next = &(&(&z * c) + &(&z_inverse * (1.0 / c))) * 0.5;
+
+        residual = (&next - &z).norm_frobenius() / next.norm_frobenius();
+        z = next;
+        iterations += 1;
+    }
+
+    // P solves [W12; W22 + I] * P = -[W11 + I; W21] in the least squares sense
+    let mut lhs = Matrix::zeros((2 * n, n));
+    let mut rhs = Matrix::zeros((2 * n, n));
+    for i in 0..n {
+        for j in 0..n {
+            let identity = if i == j { 1.0 } else { 0.0 };
+            lhs[(i, j)] = z[(i, n + j)];
+            lhs[(n + i, j)] = z[(n + i, n + j)] + identity;
+            rhs[(i, j)] = -(z[(i, j)] + identity);
+            rhs[(n + i, j)] = -z[(n + i, j)];
+        }
+    }
+    let lhs_t = lhs.T();
+    let normal_inverse = (&lhs_t * &lhs).inverse()?;
+    let mut p = &(&normal_inverse * &lhs_t) * &rhs;
+    symmetrize(&mut p);
+
+    let report = ConvergenceReport {
+        iterations,
+        residual,
+        converged: residual <= limits.tolerance,
+    };
+    return Some((p, report));
+}
+
+/// Designs the continuous LQR state feedback `u = -K * x` minimizing
+/// `integral(x^T * Q * x + u^T * R * u)` and returns `(K, P, report)`, where
+/// `P` solves the continuous algebraic Riccati equation, or `None` if `care`
+/// fails.
+pub fn lqr(
+    a: &Matrix,
+    b: &Matrix,
+    q: &Matrix,
+    r: &Matrix,
+    limits: IterationLimits,
+) -> Option<(Matrix, Matrix, ConvergenceReport)> {
+    let (p, report) = care(a, b, q, r, limits)?;
+
+    // K = R^-1 * B^T * P
+    let r_inverse = r.inverse()?;
+    let k = &(&r_inverse * &b.T()) * &p;
+
+    return Some((k, p, report));
+}
@@ -0,0 +1,249 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::matrix::Matrix;
+
+/// Discrete-time state-space model
+///
+/// `x[k + 1] = A * x[k] + B * u[k]`, `y[k] = C * x[k] + D * u[k]`
+///
+/// with n states, m inputs and p outputs. The structural properties and the
+/// transfer-function conversion hold for continuous-time models as well.
+#[derive(Debug, Clone)]
+pub struct StateSpace {
+    /// State matrix, n x n
+    pub a: Matrix,
+    /// Input matrix, n x m
+    pub b: Matrix,
+    /// Output matrix, p x n
+    pub c: Matrix,
+    /// Feedthrough matrix, p x m
+    pub d: Matrix,
+}
+
+impl StateSpace {
+    pub fn new(a: Matrix, b: Matrix, c: Matrix, d: Matrix) -> Self {
+        let n = a.m;
+        assert!(a.is_quadratic(), "A must be quadratic");
+        assert!(b.m == n, "B must have {} rows", n);
+        assert!(c.n == n, "C must have {} columns", n);
+        assert!(
+            d.shape() == (c.m, b.n),
+            "D must be of shape {}x{}",
+            c.m,
+            b.n
+        );
+
+        return Self { a, b, c, d };
+    }
+
+    /// Creates the controllable canonical form of the SISO transfer function
+    /// `num(z) / den(z)`, with coefficients in descending powers.
+    ///
+    /// The denominator is normalized to a leading coefficient of 1 and the
+    /// numerator may not have a higher degree than the denominator.
+    pub fn from_transfer_function(num: &[f32], den: &[f32]) -> Self {
+        assert!(
+            !den.is_empty() && den[0] != 0.0,
+            "Denominator must have a nonzero leading coefficient"
+        );
+        assert!(num.len() <= den.len(), "Transfer function must be proper");
+
+        let n = den.len() - 1;
+        let leading = den[0];
+        let den: Vec<f32> = den.iter().map(|e| e / leading).collect();
+
+        // Numerator padded to the degree of the denominator
+        let mut padded = vec![0.0; den.len() - num.len()];
+        padded.extend(num.iter().map(|e| e / leading));
+
+        let mut a = Matrix::zeros((n, n));
+        let mut b = Matrix::zeros((n, 1));
+        let mut c = Matrix::zeros((1, n));
+        let d = Matrix::new([[padded[0]]]);
+
+        for i in 0..n {
+            a[(0, i)] = -den[i + 1];
+            // Strictly proper remainder after removing the feedthrough
+            c[(0, i)] = padded[i + 1] - padded[0] * den[i + 1];
+        }
+        for i in 1..n {
+            a[(i, i - 1)] = 1.0;
+        }
+        if n > 0 {
+            b[(0, 0)] = 1.0;
+        }
+
+        return Self::new(a, b, c, d);
+    }
+
+    pub fn states(&self) -> usize {
+        return self.a.m;
+    }
+
+    pub fn inputs(&self) -> usize {
+        return self.b.n;
+    }
+
+    pub fn outputs(&self) -> usize {
+        return self.c.m;
+    }
+
+    /// Simulates the model from the initial state `x0`, or the zero state,
+    /// for the inputs given as columns of `u` and returns the outputs as
+    /// columns of a p x samples matrix.
+    pub fn simulate(&self, u: &Matrix, x0: Option<&Matrix>) -> Matrix {
+        assert!(
+            u.m == self.inputs(),
+            "Inputs must have {} rows",
+            self.inputs()
+        );
+
+        let mut x = match x0 {
+            Some(x0) => {
+                assert!(
+                    x0.shape() == (self.states(), 1),
+                    "Initial state must be of shape {}x1",
+                    self.states()
+                );
+                x0.clone()
+            }
+            None => Matrix::zeros((self.states(), 1)),
+        };
+
+        let mut result = Matrix::zeros((self.outputs(), u.n));
+        let mut next = Matrix::zeros((self.states(), 1));
+        let mut y = Matrix::zeros((self.outputs(), 1));
+
+        for k in 0..u.n {
+            let u_k = u.get_column(k);
+
+            self.c.mul_to(&x, &mut y);
+            y += &(&self.d * &u_k);
+            for i in 0..self.outputs() {
+                result[(i, k)] = y[(i, 0)];
+            }
+
+            self.a.mul_to(&x, &mut next);
+            next += &(&self.b * &u_k);
+            x.copy_from(&next);
+        }
+
+        return result;
+    }
+
+    /// Response of the outputs to a unit step on `input`, as columns of a
+    /// p x samples matrix.
+    pub fn step_response(&self, input: usize, samples: usize) -> Matrix {
+        assert!(input < self.inputs(), "Input out of range");
+
+        let mut u = Matrix::zeros((self.inputs(), samples));
+        for k in 0..samples {
+            u[(input, k)] = 1.0;
+        }
+
+        return self.simulate(&u, None);
+    }
+
+    /// Response of the outputs to a unit impulse on `input` at sample 0, as
+    /// columns of a p x samples matrix.
+    pub fn impulse_response(&self, input: usize, samples: usize) -> Matrix {
+        assert!(input < self.inputs(), "Input out of range");
+
+        let mut u = Matrix::zeros((self.inputs(), samples));
+        if samples > 0 {
+            u[(input, 0)] = 1.0;
+        }
+
+        return self.simulate(&u, None);
+    }
+
+    /// Returns `[B, A * B, ..., A^(n-1) * B]` of shape n x (n * m).
+    pub fn controllability_matrix(&self) -> Matrix {
+        let (n, m) = (self.states(), self.inputs());
+        let mut result = Matrix::zeros((n, n * m));
+        let mut block = self.b.clone();
+
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..m {
+                    result[(i, k * m + j)] = block[(i, j)];
+                }
+            }
+            block = &self.a * &block;
+        }
+
+        return result;
+    }
+
+    /// Returns `[C; C * A; ...; C * A^(n-1)]` of shape (n * p) x n.
+    pub fn observability_matrix(&self) -> Matrix {
+        let (n, p) = (self.states(), self.outputs());
+        let mut result = Matrix::zeros((n * p, n));
+        let mut block = self.c.clone();
+
+        for k in 0..n {
+            for i in 0..p {
+                for j in 0..n {
+                    result[(k * p + i, j)] = block[(i, j)];
+                }
+            }
+            block = &block * &self.a;
+        }
+
+        return result;
+    }
+
+    /// Checks whether the controllability matrix has full rank n.
+    pub fn is_controllable(&self) -> bool {
+        return self.controllability_matrix().rank() == self.states();
+    }
+
+    /// Checks whether the observability matrix has full rank n.
+    pub fn is_observable(&self) -> bool {
+        return self.observability_matrix().rank() == self.states();
+    }
+
+    /// Returns the transfer function from `input` to `output` as numerator
+    /// and denominator coefficients in descending powers, both of length
+    /// n + 1 with a monic denominator.
+    ///
+    /// Uses the Faddeev-LeVerrier recursion, which yields the characteristic
+    /// polynomial of `A` and the coefficients of `adj(zI - A)` together.
+    pub fn to_transfer_function(&self, output: usize, input: usize) -> (Vec<f32>, Vec<f32>) {
+        assert!(output < self.outputs(), "Output out of range");
+        assert!(input < self.inputs(), "Input out of range");
+
+        let n = self.states();
+        let b = self.b.get_column(input);
+        let c = self.c.get_rows(&[output]);
+        let d = self.d[(output, input)];
+
+        let mut den = vec![0.0; n + 1];
+        let mut num = vec![0.0; n + 1];
+        den[0] = 1.0;
+
+        // adj(zI - A) = sum of M_k * z^(n-k) with M_1 = I and
+        // M_k = A * M_(k-1) + den[k - 1] * I
+        let mut adjugate = Matrix::identity(n);
+        for k in 1..=n {
+            if k > 1 {
+                adjugate = &self.a * &adjugate;
+                for i in 0..n {
+                    adjugate[(i, i)] += den[k - 1];
+                }
+            }
+
+            let product = &self.a * &adjugate;
+            let trace: f32 = (0..n).map(|i| product[(i, i)]).sum();
+            den[k] = -trace / k as f32;
+            num[k] = (&(&c * &adjugate) * &b)[(0, 0)];
+        }
+
+        for k in 0..=n {
+            num[k] += d * den[k];
+        }
+
+        return (num, den);
+    }
+}
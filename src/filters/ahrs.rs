@@ -0,0 +1,281 @@
+use libm::sqrtf;
+
+use crate::matrix::Matrix;
+use crate::quaternion::Quaternion;
+use crate::trig::{DefaultTrig, TrigBackend};
+
+/// Attitude and heading reference filter fusing gyroscope, accelerometer and
+/// optionally magnetometer samples.
+///
+/// The orientation rotates body vectors into the earth frame, whose x-axis
+/// points to magnetic north and whose z-axis points up. Gyroscope rates are
+/// in rad/s, accelerometer and magnetometer samples may have any unit.
+pub trait AttitudeFilter {
+    /// Processes one sample taken after one sample period.
+    fn update(&mut self, gyro: [f32; 3], accel: [f32; 3], mag: Option<[f32; 3]>);
+
+    fn quaternion(&self) -> Quaternion;
+
+    /// Orientation as 3x3 rotation matrix, compatible with
+    /// `Matrix::rotation_3d`.
+    fn rotation_matrix(&self) -> Matrix {
+        return self.quaternion().to_rotation_matrix();
+    }
+
+    /// Orientation as `(yaw, pitch, roll)` for `Matrix::rotation_3d`.
+    fn euler(&self) -> (f32, f32, f32) {
+        return self.quaternion().to_euler();
+    }
+}
+
+/// Returns the unit vector, or `None` for a zero vector.
+fn normalized(v: [f32; 3]) -> Option<[f32; 3]> {
+    let norm = sqrtf(v[0] * v[0] + v[1] * v[1] + v[2] * v[2]);
+    if norm == 0.0 {
+        return None;
+    }
+    return Some([v[0] / norm, v[1] / norm, v[2] / norm]);
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    return [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ];
+}
+
+/// Rotates a body vector into the earth frame.
+fn to_earth(q: &Quaternion, v: [f32; 3]) -> [f32; 3] {
+    let rotated = *q * Quaternion::new(0.0, v[0], v[1], v[2]) * q.conjugate();
+    return [rotated.x, rotated.y, rotated.z];
+}
+
+/// Earth magnetic field reference `(horizontal, 0, vertical)` derived from
+/// the current estimate, which makes the filters insensitive to the
+/// inclination of the local field.
+fn magnetic_reference(q: &Quaternion, mag: [f32; 3]) -> (f32, f32) {
+    let h = to_earth(q, mag);
+    return (sqrtf(h[0] * h[0] + h[1] * h[1]), h[2]);
+}
+
+/// Madgwick's gradient descent filter.
+///
+/// Each step corrects the gyroscope integration by `beta` along the gradient
+/// of the accelerometer and magnetometer misfit.
+#[derive(Debug, Clone)]
+pub struct MadgwickFilter {
+    pub orientation: Quaternion,
+    /// Sample period in seconds
+    pub sample_period: f32,
+    /// Correction gain in rad/s, about `sqrt(3/4)` times the gyroscope
+    /// noise.
+    pub beta: f32,
+}
+
+impl MadgwickFilter {
+    pub fn new(sample_period: f32, beta: f32) -> Self {
+        return Self {
+            orientation: Quaternion::identity(),
+            sample_period,
+            beta,
+        };
+    }
+}
+
+impl AttitudeFilter for MadgwickFilter {
+    fn update(&mut self, gyro: [f32; 3], accel: [f32; 3], mag: Option<[f32; 3]>) {
+        let q = self.orientation;
+        let Quaternion { w, x, y, z } = q;
+
+        // Rate of change from the gyroscope, q' = q * (0, ω) / 2
+        let mut derivative = q * Quaternion::new(0.0, gyro[0], gyro[1], gyro[2]) * 0.5;
+
+        if let Some(a) = normalized(accel) {
+            // Misfit of the expected and measured gravity direction
+            let f = [
+                2.0 * (x * z - w * y) - a[0],
+                2.0 * (w * x + y * z) - a[1],
+                1.0 - 2.0 * (x * x + y * y) - a[2],
+            ];
+            // Gradient J^T * f
+            let mut step = Quaternion::new(
+                -2.0 * y * f[0] + 2.0 * x * f[1],
+                2.0 * z * f[0] + 2.0 * w * f[1] - 4.0 * x * f[2],
+                -2.0 * w * f[0] + 2.0 * z * f[1] - 4.0 * y * f[2],
+                2.0 * x * f[0] + 2.0 * y * f[1],
+            );
+
+            if let Some(m) = mag.and_then(normalized) {
+                let (bx, bz) = magnetic_reference(&q, m);
+
+                // Misfit of the expected and measured magnetic field direction
+                let f = [
+                    bx * (1.0 - 2.0 * (y * y + z * z)) + bz * 2.0 * (x * z - w * y) - m[0],
+                    bx * 2.0 * (x * y - w * z) + bz * 2.0 * (w * x + y * z) - m[1],
+                    bx * 2.0 * (x * z + w * y) + bz * (1.0 - 2.0 * (x * x + y * y)) - m[2],
+                ];
+                step = step
+                    + Quaternion::new(
+                        -2.0 * bz * y * f[0]
+                            + (-2.0 * bx * z + 2.0 * bz * x) * f[1]
+                            + 2.0 * bx * y * f[2],
+                        2.0 * bz * z * f[0]
+                            + (2.0 * bx * y + 2.0 * bz * w) * f[1]
+                            + (2.0 * bx * z - 4.0 * bz * x) * f[2],
+                        (-4.0 * bx * y - 2.0 * bz * w) * f[0]
+                            + (2.0 * bx * x + 2.0 * bz * z) * f[1]
+                            + (2.0 * bx * w - 4.0 * bz * y) * f[2],
+                        (-4.0 * bx * z + 2.0 * bz * x) * f[0]
+                            + (-2.0 * bx * w + 2.0 * bz * y) * f[1]
+                            + 2.0 * bx * x * f[2],
+                    );
+            }
+
+            let norm = step.norm();
+            if norm > 0.0 {
+                derivative = derivative + step * (-self.beta / norm);
+            }
+        }
+
+        self.orientation = (q + derivative * self.sample_period).normalized();
+    }
+
+    fn quaternion(&self) -> Quaternion {
+        return self.orientation;
+    }
+}
+
+/// Mahony's nonlinear complementary filter on SO(3).
+///
+/// A PI controller feeds the misalignment of the measured and the expected
+/// reference directions back into the gyroscope rates, so the integral term
+/// also estimates the gyroscope bias.
+#[derive(Debug, Clone)]
+pub struct MahonyFilter {
+    pub orientation: Quaternion,
+    /// Sample period in seconds
+    pub sample_period: f32,
+    /// Proportional gain in rad/s
+    pub kp: f32,
+    /// Integral gain in rad/s², 0 disables the bias estimation
+    pub ki: f32,
+    /// Estimated gyroscope bias in rad/s, subtracted from the rates
+    pub bias: [f32; 3],
+}
+
+impl MahonyFilter {
+    pub fn new(sample_period: f32, kp: f32, ki: f32) -> Self {
+        return Self {
+            orientation: Quaternion::identity(),
+            sample_period,
+            kp,
+            ki,
+            bias: [0.0; 3],
+        };
+    }
+}
+
+impl AttitudeFilter for MahonyFilter {
+    fn update(&mut self, gyro: [f32; 3], accel: [f32; 3], mag: Option<[f32; 3]>) {
+        let q = self.orientation;
+        let Quaternion { w, x, y, z } = q;
+        let mut error = [0.0; 3];
+
+        if let Some(a) = normalized(accel) {
+            // Expected gravity direction in the body frame
+            let v = [
+                2.0 * (x * z - w * y),
+                2.0 * (w * x + y * z),
+                1.0 - 2.0 * (x * x + y * y),
+            ];
+            error = cross(a, v);
+
+            if let Some(m) = mag.and_then(normalized) {
+                let (bx, bz) = magnetic_reference(&q, m);
+
+                // Expected magnetic field direction in the body frame
+                let v = [
+                    bx * (1.0 - 2.0 * (y * y + z * z)) + bz * 2.0 * (x * z - w * y),
+                    bx * 2.0 * (x * y - w * z) + bz * 2.0 * (w * x + y * z),
+                    bx * 2.0 * (x * z + w * y) + bz * (1.0 - 2.0 * (x * x + y * y)),
+                ];
+                let mag_error = cross(m, v);
+                for i in 0..3 {
+                    error[i] += mag_error[i];
+                }
+            }
+        }
+
+        let mut rates = [0.0; 3];
+        for i in 0..3 {
+            self.bias[i] -= self.ki * error[i] * self.sample_period;
+            rates[i] = gyro[i] - self.bias[i] + self.kp * error[i];
+        }
+
+        self.orientation.integrate(rates, self.sample_period);
+    }
+
+    fn quaternion(&self) -> Quaternion {
+        return self.orientation;
+    }
+}
+
+/// Complementary filter blending the integrated gyroscope orientation with
+/// the orientation measured by the accelerometer and magnetometer.
+///
+/// Without magnetometer the heading is carried by the gyroscope alone.
+#[derive(Debug, Clone)]
+pub struct ComplementaryFilter {
+    pub orientation: Quaternion,
+    /// Sample period in seconds
+    pub sample_period: f32,
+    /// Weight of the measured orientation per sample, in `[0, 1]`
+    pub gain: f32,
+}
+
+impl ComplementaryFilter {
+    pub fn new(sample_period: f32, gain: f32) -> Self {
+        return Self {
+            orientation: Quaternion::identity(),
+            sample_period,
+            gain,
+        };
+    }
+}
+
+impl AttitudeFilter for ComplementaryFilter {
+    fn update(&mut self, gyro: [f32; 3], accel: [f32; 3], mag: Option<[f32; 3]>) {
+        let mut predicted = self.orientation;
+        predicted.integrate(gyro, self.sample_period);
+
+        let a = match normalized(accel) {
+            Some(a) => a,
+            None => {
+                self.orientation = predicted;
+                return;
+            }
+        };
+
+        // Tilt from the gravity direction
+        let roll = DefaultTrig::atan2(a[1], a[2]);
+        let pitch = DefaultTrig::atan2(-a[0], sqrtf(a[1] * a[1] + a[2] * a[2]));
+
+        let yaw = match mag.and_then(normalized) {
+            Some(m) => {
+                // Level the magnetic field and take its heading
+                let level = Quaternion::from_euler(0.0, pitch, roll);
+                let h = to_earth(&level, m);
+                DefaultTrig::atan2(-h[1], h[0])
+            }
+            None => predicted.to_euler().0,
+        };
+
+        let measured = Quaternion::from_euler(yaw, pitch, roll);
+        self.orientation = predicted.nlerp(&measured, self.gain);
+    }
+
+    fn quaternion(&self) -> Quaternion {
+        return self.orientation;
+    }
+}
@@ -0,0 +1,62 @@
+use crate::matrix::Matrix;
+
+use super::{GaussianEstimate, MeasurementModel, ProcessModel, UpdateReport};
+
+/// Extended Kalman filter, linearizing the models around the current
+/// estimate.
+///
+/// The measurement model is passed to each update, so several sensors can
+/// correct the same filter.
+#[derive(Debug, Clone)]
+pub struct ExtendedKalmanFilter<P: ProcessModel> {
+    pub estimate: GaussianEstimate,
+    pub process: P,
+    /// Process noise covariance, n x n
+    pub q: Matrix,
+}
+
+impl<P: ProcessModel> ExtendedKalmanFilter<P> {
+    pub fn new(estimate: GaussianEstimate, process: P, q: Matrix) -> Self {
+        assert!(
+            q.shape() == estimate.p.shape(),
+            "Q must be of shape {}x{}",
+            estimate.p.m,
+            estimate.p.n
+        );
+
+        return Self {
+            estimate,
+            process,
+            q,
+        };
+    }
+
+    /// Propagates the state with `x = f(x, u)` and the covariance with
+    /// `P = F * P * F^T + Q`, where `F` is the Jacobian of `f`.
+    pub fn predict(&mut self, u: Option<&Matrix>) {
+        let x = &self.estimate.x;
+        let f = self.process.jacobian(x, u);
+
+        self.estimate.x = self.process.predict(x, u);
+        self.estimate.p = &(&(&f * &self.estimate.p) * &f.T()) + &self.q;
+    }
+
+    /// Corrects the state with the measurement `z` of the model `h` with the
+    /// noise covariance `r`.
+    pub fn update<M: MeasurementModel>(&mut self, h: &M, z: &Matrix, r: &Matrix) -> UpdateReport {
+        let x = &self.estimate.x;
+        let jacobian = h.jacobian(x);
+        let innovation = z - &h.measure(x);
+
+        // P_xz = P * H^T, S = H * P * H^T + R
+        let cross_covariance = &self.estimate.p * &jacobian.T();
+        let innovation_covariance = &(&jacobian * &cross_covariance) + r;
+
+        return self.estimate.correct(
+            innovation,
+            innovation_covariance,
+            &cross_covariance,
+            Some((&jacobian, r)),
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use crate::matrix::Matrix;
+
+use super::UpdateReport;
+
+/// Gaussian state estimate shared by the extended and unscented Kalman
+/// filters.
+#[derive(Debug, Clone)]
+pub struct GaussianEstimate {
+    /// State estimate, n x 1
+    pub x: Matrix,
+    /// State covariance, n x n
+    pub p: Matrix,
+    /// Measurements with a NIS above this threshold are rejected.
+    pub gate: Option<f32>,
+    innovation: Matrix,
+    innovation_covariance: Matrix,
+}
+
+impl GaussianEstimate {
+    pub fn new(x: Matrix, p: Matrix) -> Self {
+        assert!(x.is_column_vector(), "State must be a column vector");
+        assert!(
+            p.shape() == (x.m, x.m),
+            "Covariance must be of shape {}x{}",
+            x.m,
+            x.m
+        );
+
+        return Self {
+            x,
+            p,
+            gate: None,
+            innovation: Matrix::zeros((0, 1)),
+            innovation_covariance: Matrix::zeros((0, 0)),
+        };
+    }
+
+    /// Innovation `y = z - h(x)` of the last update.
+    pub fn innovation(&self) -> &Matrix {
+        return &self.innovation;
+    }
+
+    /// Innovation covariance `S` of the last update.
+    pub fn innovation_covariance(&self) -> &Matrix {
+        return &self.innovation_covariance;
+    }
+
+    /// Report of an update that couldn't be applied because a covariance
+    /// isn't positive definite.
+    pub(super) fn rejected() -> UpdateReport {
+        return UpdateReport {
+            nis: f32::NAN,
+            accepted: false,
+        };
+    }
+
+    /// Applies the Kalman correction for the innovation `y`, its covariance
+    /// `S` and the state-measurement cross covariance `P_xz`:
+    /// `K = P_xz * S^-1` and `x = x + K * y`.
+    ///
+    /// With the measurement matrix `H` and the noise covariance `R` of a
+    /// linear(ized) model in `joseph`, the covariance is updated in the
+    /// Joseph form `P = (I - K * H) * P * (I - K * H)^T + K * R * K^T` like
+    /// `KalmanFilter`, which keeps `P` symmetric positive definite. Without,
+    /// it is updated with `P = P - K * P_xz^T`, which also holds for the
+    /// sigma point estimates of nonlinear models.
+    pub(super) fn correct(
+        &mut self,
+        innovation: Matrix,
+        innovation_covariance: Matrix,
+        cross_covariance: &Matrix,
+        joseph: Option<(&Matrix, &Matrix)>,
+    ) -> UpdateReport {
+        self.innovation = innovation;
+        self.innovation_covariance = innovation_covariance;
+
+        let cholesky = match self.innovation_covariance.cholesky() {
+            Some(cholesky) => cholesky,
+            None => return Self::rejected(),
+        };
+
+        // NIS = y^T * S^-1 * y
+        let mut weighted_innovation = self.innovation.clone();
+        cholesky.cholesky_solve_ip(&mut weighted_innovation);
+        let nis = self.innovation.T().dot(&weighted_innovation);
+
+        if let Some(gate) = self.gate {
+            if nis > gate {
+                return UpdateReport {
+                    nis,
+                    accepted: false,
+                };
+            }
+        }
+
+        // K^T = S^-1 * P_xz^T as S is symmetric
+        let mut gain = cross_covariance.T();
+        cholesky.cholesky_solve_ip(&mut gain);
+        gain.T_ip();
+
+        self.x += &(&gain * &self.innovation);
+        match joseph {
+            Some((measurement_matrix, r)) => {
+                let i_kh = &Matrix::identity(self.p.m) - &(&gain * measurement_matrix);
+                self.p = &(&(&i_kh * &self.p) * &i_kh.T()) + &(&(&gain * r) * &gain.T());
+            }
+            None => self.p -= &(&gain * &cross_covariance.T()),
+        }
+
+        // Remove the asymmetry left by rounding errors
+        for i in 0..self.p.m {
+            for j in 0..i {
+                let mean = (self.p[(i, j)] + self.p[(j, i)]) / 2.0;
+                self.p[(i, j)] = mean;
+                self.p[(j, i)] = mean;
+            }
+        }
+
+        return UpdateReport {
+            nis,
+            accepted: true,
+        };
+    }
+}
@@ -0,0 +1,255 @@
+use crate::matrix::Matrix;
+
+/// Diagnostics of a measurement update.
+#[derive(Debug, Clone, Copy)]
+pub struct UpdateReport {
+    /// Normalized innovation squared `y^T * S^-1 * y`. `NaN` if the
+    /// measurement was rejected for a covariance that isn't positive
+    /// definite.
+    pub nis: f32,
+    /// `false` if the measurement was rejected by the gate or because `S`,
+    /// or `P` in the unscented filter, isn't positive definite. The state is
+    /// left unchanged then.
+    pub accepted: bool,
+}
+
+/// Preallocated intermediate results, so steady-state steps don't allocate.
+///
+/// `n` is the state and `m` the measurement dimension.
+#[derive(Debug, Clone)]
+struct Workspace {
+    /// n x 1
+    state: Matrix,
+    /// n x 1
+    control: Matrix,
+    /// n x n
+    covariance: Matrix,
+    /// n x n, `I - K * H`
+    joseph: Matrix,
+    /// m x 1, `y = z - H * x`
+    innovation: Matrix,
+    /// m x 1, `S^-1 * y`
+    weighted_innovation: Matrix,
+    /// m x m, `S = H * P * H^T + R`
+    innovation_covariance: Matrix,
+    /// m x m, Cholesky factor of `S`
+    cholesky: Matrix,
+    /// m x n, `H * P` and later `S^-1 * H * P`
+    projected: Matrix,
+    /// n x m, Kalman gain `K`
+    gain: Matrix,
+    /// n x m, `K * R`
+    gain_noise: Matrix,
+}
+
+impl Workspace {
+    fn new(n: usize, m: usize) -> Self {
+        return Self {
+            state: Matrix::zeros((n, 1)),
+            control: Matrix::zeros((n, 1)),
+            covariance: Matrix::zeros((n, n)),
+            joseph: Matrix::zeros((n, n)),
+            innovation: Matrix::zeros((m, 1)),
+            weighted_innovation: Matrix::zeros((m, 1)),
+            innovation_covariance: Matrix::zeros((m, m)),
+            cholesky: Matrix::zeros((m, m)),
+            projected: Matrix::zeros((m, n)),
+            gain: Matrix::zeros((n, m)),
+            gain_noise: Matrix::zeros((n, m)),
+        };
+    }
+}
+
+/// Linear Kalman filter for the model
+///
+/// `x' = F * x + B * u + w` with `w ~ N(0, Q)`,
+/// `z = H * x + v` with `v ~ N(0, R)`.
+///
+/// The model matrices are public so they can be changed between steps, as
+/// long as their shapes stay the same.
+#[derive(Debug, Clone)]
+pub struct KalmanFilter {
+    /// State estimate, n x 1
+    pub x: Matrix,
+    /// State covariance, n x n
+    pub p: Matrix,
+    /// State transition, n x n
+    pub f: Matrix,
+    /// Control input, n x k
+    pub b: Matrix,
+    /// Measurement model, m x n
+    pub h: Matrix,
+    /// Process noise covariance, n x n
+    pub q: Matrix,
+    /// Measurement noise covariance, m x m
+    pub r: Matrix,
+    /// Measurements with a NIS above this threshold are rejected. For a
+    /// m-dimensional measurement the NIS is chi-squared distributed with m
+    /// degrees of freedom.
+    pub gate: Option<f32>,
+    workspace: Workspace,
+}
+
+impl KalmanFilter {
+    pub fn new(
+        x: Matrix,
+        p: Matrix,
+        f: Matrix,
+        b: Matrix,
+        h: Matrix,
+        q: Matrix,
+        r: Matrix,
+    ) -> Self {
+        let n = x.m;
+        let m = h.m;
+        assert!(x.is_column_vector(), "State must be a column vector");
+        assert!(
+            p.shape() == (n, n),
+            "Covariance must be of shape {}x{}",
+            n,
+            n
+        );
+        assert!(f.shape() == (n, n), "F must be of shape {}x{}", n, n);
+        assert!(b.m == n, "B must have {} rows", n);
+        assert!(h.n == n, "H must have {} columns", n);
+        assert!(q.shape() == (n, n), "Q must be of shape {}x{}", n, n);
+        assert!(r.shape() == (m, m), "R must be of shape {}x{}", m, m);
+
+        return Self {
+            x,
+            p,
+            f,
+            b,
+            h,
+            q,
+            r,
+            gate: None,
+            workspace: Workspace::new(n, m),
+        };
+    }
+
+    /// Innovation `y = z - H * x` of the last update.
+    pub fn innovation(&self) -> &Matrix {
+        return &self.workspace.innovation;
+    }
+
+    /// Innovation covariance `S = H * P * H^T + R` of the last update.
+    pub fn innovation_covariance(&self) -> &Matrix {
+        return &self.workspace.innovation_covariance;
+    }
+
+    /// Kalman gain `K` of the last accepted update.
+    pub fn gain(&self) -> &Matrix {
+        return &self.workspace.gain;
+    }
+
+    /// Propagates the state with `x = F * x + B * u` and the covariance with
+    /// `P = F * P * F^T + Q`.
+    pub fn predict(&mut self, u: Option<&Matrix>) {
+        let ws = &mut self.workspace;
+
+        self.f.mul_to(&self.x, &mut ws.state);
+        if let Some(u) = u {
+            self.b.mul_to(u, &mut ws.control);
+            ws.state += &ws.control;
+        }
+        self.x.copy_from(&ws.state);
+
+        self.f.mul_to(&self.p, &mut ws.covariance);
+        ws.covariance.mul_transposed_to(&self.f, &mut self.p);
+        self.p += &self.q;
+    }
+
+    /// Corrects the state with the measurement `z`, using the Joseph form
+    /// `P = (I - K * H) * P * (I - K * H)^T + K * R * K^T`, which keeps `P`
+    /// symmetric positive definite.
+    pub fn update(&mut self, z: &Matrix) -> UpdateReport {
+        let ws = &mut self.workspace;
+        let n = self.x.m;
+        let m = self.h.m;
+        assert!(
+            z.is_column_vector() && z.len() == m,
+            "Measurement must be a column vector of length {}",
+            m
+        );
+
+        // y = z - H * x
+        self.h.mul_to(&self.x, &mut ws.innovation);
+        for i in 0..m {
+            ws.innovation[(i, 0)] = z[(i, 0)] - ws.innovation[(i, 0)];
+        }
+
+        // S = H * P * H^T + R
+        self.h.mul_to(&self.p, &mut ws.projected);
+        ws.projected
+            .mul_transposed_to(&self.h, &mut ws.innovation_covariance);
+        ws.innovation_covariance += &self.r;
+
+        ws.cholesky.copy_from(&ws.innovation_covariance);
+        if !ws.cholesky.cholesky_ip() {
+            return UpdateReport {
+                nis: f32::NAN,
+                accepted: false,
+            };
+        }
+
+        // NIS = y^T * S^-1 * y
+        ws.weighted_innovation.copy_from(&ws.innovation);
+        ws.cholesky.cholesky_solve_ip(&mut ws.weighted_innovation);
+        let nis: f32 = (0..m)
+            .map(|i| ws.innovation[(i, 0)] * ws.weighted_innovation[(i, 0)])
+            .sum();
+
+        if let Some(gate) = self.gate {
+            if nis > gate {
+                return UpdateReport {
+                    nis,
+                    accepted: false,
+                };
+            }
+        }
+
+        // K = P * H^T * S^-1 = (S^-1 * H * P)^T as P and S are symmetric
+        ws.cholesky.cholesky_solve_ip(&mut ws.projected);
+        for i in 0..n {
+            for j in 0..m {
+                ws.gain[(i, j)] = ws.projected[(j, i)];
+            }
+        }
+
+        // x = x + K * y
+        ws.gain.mul_to(&ws.innovation, &mut ws.state);
+        self.x += &ws.state;
+
+        // I - K * H
+        ws.gain.mul_to(&self.h, &mut ws.joseph);
+        for i in 0..n {
+            for j in 0..n {
+                let identity = if i == j { 1.0 } else { 0.0 };
+                ws.joseph[(i, j)] = identity - ws.joseph[(i, j)];
+            }
+        }
+
+        // P = (I - K * H) * P * (I - K * H)^T + K * R * K^T
+        ws.joseph.mul_to(&self.p, &mut ws.covariance);
+        ws.covariance.mul_transposed_to(&ws.joseph, &mut self.p);
+        ws.gain.mul_to(&self.r, &mut ws.gain_noise);
+        ws.gain_noise
+            .mul_transposed_to(&ws.gain, &mut ws.covariance);
+        self.p += &ws.covariance;
+
+        // Remove the asymmetry left by rounding errors
+        for i in 0..n {
+            for j in 0..i {
+                let mean = (self.p[(i, j)] + self.p[(j, i)]) / 2.0;
+                self.p[(i, j)] = mean;
+                self.p[(j, i)] = mean;
+            }
+        }
+
+        return UpdateReport {
+            nis,
+            accepted: true,
+        };
+    }
+}
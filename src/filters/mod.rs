@@ -0,0 +1,13 @@
+mod ahrs;
+mod ekf;
+mod estimate;
+mod kalman;
+mod models;
+mod ukf;
+
+pub use ahrs::{AttitudeFilter, ComplementaryFilter, MadgwickFilter, MahonyFilter};
+pub use ekf::ExtendedKalmanFilter;
+pub use estimate::GaussianEstimate;
+pub use kalman::{KalmanFilter, UpdateReport};
+pub use models::{MeasurementModel, ProcessModel};
+pub use ukf::{SigmaPointParameters, UnscentedKalmanFilter};
@@ -0,0 +1,76 @@
+use crate::matrix::Matrix;
+
+/// Relative step of the central differences used for numerical Jacobians.
+const JACOBIAN_STEP: f32 = 1e-3;
+
+/// Central difference Jacobian `df/dx` of a vector function at `x`.
+fn numerical_jacobian(x: &Matrix, f: impl Fn(&Matrix) -> Matrix) -> Matrix {
+    let columns = x.len();
+    let mut result: Option<Matrix> = None;
+    let mut shifted = x.clone();
+
+    for n in 0..columns {
+        let step = JACOBIAN_STEP * f32::max(1.0, x[(n, 0)].abs());
+
+        shifted[(n, 0)] = x[(n, 0)] + step;
+        let upper = f(&shifted);
+        shifted[(n, 0)] = x[(n, 0)] - step;
+        let lower = f(&shifted);
+        shifted[(n, 0)] = x[(n, 0)];
+
+        let jacobian = result.get_or_insert_with(|| Matrix::zeros((upper.len(), columns)));
+        for m in 0..upper.len() {
+            jacobian[(m, n)] = (upper[(m, 0)] - lower[(m, 0)]) / (2.0 * step);
+        }
+    }
+
+    return result.unwrap_or_else(|| Matrix::zeros((0, 0)));
+}
+
+/// Nonlinear state transition `x' = f(x, u)`.
+///
+/// Closures `Fn(&Matrix, Option<&Matrix>) -> Matrix` implement this trait
+/// with a numerical Jacobian.
+pub trait ProcessModel {
+    /// Returns the propagated state column vector.
+    fn predict(&self, x: &Matrix, u: Option<&Matrix>) -> Matrix;
+
+    /// Returns the Jacobian `df/dx` at `x`. Defaults to central differences;
+    /// override it with the analytical Jacobian where available.
+    fn jacobian(&self, x: &Matrix, u: Option<&Matrix>) -> Matrix {
+        return numerical_jacobian(x, |x| self.predict(x, u));
+    }
+}
+
+/// Nonlinear measurement function `z = h(x)`.
+///
+/// Closures `Fn(&Matrix) -> Matrix` implement this trait with a numerical
+/// Jacobian.
+pub trait MeasurementModel {
+    /// Returns the expected measurement column vector.
+    fn measure(&self, x: &Matrix) -> Matrix;
+
+    /// Returns the Jacobian `dh/dx` at `x`. Defaults to central differences;
+    /// override it with the analytical Jacobian where available.
+    fn jacobian(&self, x: &Matrix) -> Matrix {
+        return numerical_jacobian(x, |x| self.measure(x));
+    }
+}
+
+impl<F> ProcessModel for F
+where
+    F: Fn(&Matrix, Option<&Matrix>) -> Matrix,
+{
+    fn predict(&self, x: &Matrix, u: Option<&Matrix>) -> Matrix {
+        return self(x, u);
+    }
+}
+
+impl<F> MeasurementModel for F
+where
+    F: Fn(&Matrix) -> Matrix,
+{
+    fn measure(&self, x: &Matrix) -> Matrix {
+        return self(x);
+    }
+}
@@ -0,0 +1,193 @@
+use alloc::vec::Vec;
+
+use crate::matrix::Matrix;
+
+use super::{GaussianEstimate, MeasurementModel, ProcessModel, UpdateReport};
+
+/// Scaling of the sigma points, see Van der Merwe's scaled unscented
+/// transform.
+#[derive(Debug, Clone, Copy)]
+pub struct SigmaPointParameters {
+    /// Spread of the sigma points around the mean, in `(0, 1]`. Small values
+    /// produce large weights of opposite sign, which costs precision in f32.
+    pub alpha: f32,
+    /// Prior knowledge of the distribution, 2 is optimal for Gaussians.
+    pub beta: f32,
+    /// Secondary scaling, usually 0 or `3 - n`.
+    pub kappa: f32,
+}
+
+impl Default for SigmaPointParameters {
+    fn default() -> Self {
+        return Self {
+            alpha: 1.0,
+            beta: 2.0,
+            kappa: 0.0,
+        };
+    }
+}
+
+/// Unscented Kalman filter, propagating 2n + 1 sigma points through the
+/// nonlinear models instead of linearizing them.
+///
+/// The measurement model is passed to each update, so several sensors can
+/// correct the same filter.
+#[derive(Debug, Clone)]
+pub struct UnscentedKalmanFilter<P: ProcessModel> {
+    pub estimate: GaussianEstimate,
+    pub process: P,
+    /// Process noise covariance, n x n
+    pub q: Matrix,
+    lambda: f32,
+    mean_weights: Vec<f32>,
+    covariance_weights: Vec<f32>,
+}
+
+impl<P: ProcessModel> UnscentedKalmanFilter<P> {
+    pub fn new(
+        estimate: GaussianEstimate,
+        process: P,
+        q: Matrix,
+        parameters: SigmaPointParameters,
+    ) -> Self {
+        assert!(
+            q.shape() == estimate.p.shape(),
+            "Q must be of shape {}x{}",
+            estimate.p.m,
+            estimate.p.n
+        );
+
+        let n = estimate.x.len() as f32;
+        let SigmaPointParameters { alpha, beta, kappa } = parameters;
+        let lambda = alpha * alpha * (n + kappa) - n;
+
+        let count = 2 * estimate.x.len() + 1;
+        let mut mean_weights = Vec::with_capacity(count);
+        let mut covariance_weights = Vec::with_capacity(count);
+
+        mean_weights.push(lambda / (n + lambda));
+        covariance_weights.push(lambda / (n + lambda) + 1.0 - alpha * alpha + beta);
+        for _ in 1..count {
+            mean_weights.push(1.0 / (2.0 * (n + lambda)));
+            covariance_weights.push(1.0 / (2.0 * (n + lambda)));
+        }
+
+        return Self {
+            estimate,
+            process,
+            q,
+            lambda,
+            mean_weights,
+            covariance_weights,
+        };
+    }
+
+    /// Returns the sigma points of the current estimate as the columns of a
+    /// n x (2n + 1) matrix, spread by the Cholesky factor of `(n + λ) * P`,
+    /// or `None` if `P` isn't positive definite.
+    pub fn sigma_points(&self) -> Option<Matrix> {
+        let x = &self.estimate.x;
+        let n = x.len();
+
+        let scaled = &self.estimate.p * (n as f32 + self.lambda);
+        let root = scaled.cholesky()?;
+
+        let mut result = Matrix::zeros((n, 2 * n + 1));
+        for m in 0..n {
+            result[(m, 0)] = x[(m, 0)];
+            for k in 0..n {
+                result[(m, 1 + k)] = x[(m, 0)] + root[(m, k)];
+                result[(m, 1 + n + k)] = x[(m, 0)] - root[(m, k)];
+            }
+        }
+
+        return Some(result);
+    }
+
+    /// Weighted mean and covariance of the columns of `points`.
+    fn statistics(&self, points: &Matrix) -> (Matrix, Matrix) {
+        let mut mean = Matrix::zeros((points.m, 1));
+        for k in 0..points.n {
+            for m in 0..points.m {
+                mean[(m, 0)] += self.mean_weights[k] * points[(m, k)];
+            }
+        }
+
+        let covariance = self.cross_covariance(points, &mean, points, &mean);
+        return (mean, covariance);
+    }
+
+    /// Weighted cross covariance of two sets of sigma points around their
+    /// means.
+    fn cross_covariance(&self, a: &Matrix, a_mean: &Matrix, b: &Matrix, b_mean: &Matrix) -> Matrix {
+        let mut result = Matrix::zeros((a.m, b.m));
+        for k in 0..a.n {
+            let weight = self.covariance_weights[k];
+            for i in 0..a.m {
+                for j in 0..b.m {
+                    result[(i, j)] +=
+                        weight * (a[(i, k)] - a_mean[(i, 0)]) * (b[(j, k)] - b_mean[(j, 0)]);
+                }
+            }
+        }
+
+        return result;
+    }
+
+    /// Propagates each column of `points` through `f` into the columns of a
+    /// new matrix.
+    fn transform(points: &Matrix, f: impl Fn(&Matrix) -> Matrix) -> Matrix {
+        let mut result: Option<Matrix> = None;
+
+        for k in 0..points.n {
+            let transformed = f(&points.get_column(k));
+            let result = result.get_or_insert_with(|| Matrix::zeros((transformed.len(), points.n)));
+            for m in 0..transformed.len() {
+                result[(m, k)] = transformed[(m, 0)];
+            }
+        }
+
+        return result.unwrap();
+    }
+
+    /// Propagates the sigma points with `x = f(x, u)` and recovers the mean
+    /// and the covariance plus `Q` from them.
+    ///
+    /// Returns `false` and leaves the estimate unchanged if `P` isn't
+    /// positive definite.
+    pub fn predict(&mut self, u: Option<&Matrix>) -> bool {
+        let points = match self.sigma_points() {
+            Some(points) => points,
+            None => return false,
+        };
+        let propagated = Self::transform(&points, |x| self.process.predict(x, u));
+        let (x, p) = self.statistics(&propagated);
+
+        self.estimate.x = x;
+        self.estimate.p = &p + &self.q;
+        return true;
+    }
+
+    /// Corrects the state with the measurement `z` of the model `h` with the
+    /// noise covariance `r`.
+    ///
+    /// The measurement is rejected with a NaN NIS if `P` isn't positive
+    /// definite.
+    pub fn update<M: MeasurementModel>(&mut self, h: &M, z: &Matrix, r: &Matrix) -> UpdateReport {
+        let points = match self.sigma_points() {
+            Some(points) => points,
+            None => return GaussianEstimate::rejected(),
+        };
+        let measurements = Self::transform(&points, |x| h.measure(x));
+        let (z_mean, z_covariance) = self.statistics(&measurements);
+
+        let cross_covariance =
+            self.cross_covariance(&points, &self.estimate.x, &measurements, &z_mean);
+        let innovation = z - &z_mean;
+        let innovation_covariance = &z_covariance + r;
+
+        return self
+            .estimate
+            .correct(innovation, innovation_covariance, &cross_covariance, None);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::matrix::Matrix;
+
+use super::{Breakpoints, Extrapolation};
+
+/// Two-dimensional lookup table with bilinear interpolation, where
+/// `values[(i, j)]` is the value at `(x[i], y[j])`.
+#[derive(Debug, Clone)]
+pub struct BilinearTable {
+    x: Breakpoints,
+    y: Breakpoints,
+    values: Matrix,
+    pub extrapolation: Extrapolation,
+}
+
+impl BilinearTable {
+    /// Creates the table over the grid of the strictly increasing
+    /// breakpoints `x` along the rows and `y` along the columns of `values`.
+    pub fn new(x: &[f32], y: &[f32], values: Matrix, extrapolation: Extrapolation) -> Self {
+        assert!(
+            values.shape() == (x.len(), y.len()),
+            "Values must be of shape {}x{}",
+            x.len(),
+            y.len()
+        );
+
+        return Self {
+            x: Breakpoints::new(x),
+            y: Breakpoints::new(y),
+            values,
+            extrapolation,
+        };
+    }
+
+    pub fn values(&self) -> &Matrix {
+        return &self.values;
+    }
+
+    /// Applies the extrapolation policy to one coordinate and returns it,
+    /// or `None` for NaN results.
+    fn limit(&self, breakpoints: &Breakpoints, coordinate: f32) -> Option<f32> {
+        let outside = coordinate < breakpoints.first() || coordinate > breakpoints.last();
+        if !outside {
+            return Some(coordinate);
+        }
+
+        return match self.extrapolation {
+            Extrapolation::Nan => None,
+            Extrapolation::Clamp => Some(coordinate.clamp(breakpoints.first(), breakpoints.last())),
+            // The border cells simply continue
+            Extrapolation::Linear => Some(coordinate),
+        };
+    }
+
+    pub fn evaluate(&self, x: f32, y: f32) -> f32 {
+        let (x, y) = match (self.limit(&self.x, x), self.limit(&self.y, y)) {
+            (Some(x), Some(y)) => (x, y),
+            _ => return f32::NAN,
+        };
+
+        let i = self.x.segment(x);
+        let j = self.y.segment(y);
+        let s = (x - self.x.points[i]) / self.x.width(i);
+        let t = (y - self.y.points[j]) / self.y.width(j);
+
+        let v = &self.values;
+        let lower = v[(i, j)] + s * (v[(i + 1, j)] - v[(i, j)]);
+        let upper = v[(i, j + 1)] + s * (v[(i + 1, j + 1)] - v[(i, j + 1)]);
+        return lower + t * (upper - lower);
+    }
+
+    /// Evaluates the table at the coordinate pairs `(x[(m, n)], y[(m, n)])`.
+    pub fn evaluate_matrix(&self, x: &Matrix, y: &Matrix) -> Matrix {
+        assert!(
+            x.shape() == y.shape(),
+            "Coordinates must have the same shape"
+        );
+
+        let mut result = Matrix::zeros(x.shape());
+        for m in 0..x.m {
+            for n in 0..x.n {
+                result[(m, n)] = self.evaluate(x[(m, n)], y[(m, n)]);
+            }
+        }
+        return result;
+    }
+}
@@ -0,0 +1,203 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::matrix::{Matrix, TridiagonalMatrix};
+
+use super::{Breakpoints, Extrapolation, Interpolator};
+
+/// Piecewise cubic Hermite interpolation, defined by the values and the
+/// slopes at the breakpoints.
+#[derive(Debug, Clone)]
+pub struct CubicInterpolator {
+    x: Breakpoints,
+    y: Vec<f32>,
+    slopes: Vec<f32>,
+    pub extrapolation: Extrapolation,
+}
+
+/// Slopes of the linear segments between the points.
+fn secants(x: &Breakpoints, y: &[f32]) -> Vec<f32> {
+    return (0..x.len() - 1)
+        .map(|i| (y[i + 1] - y[i]) / x.width(i))
+        .collect();
+}
+
+impl CubicInterpolator {
+    /// Creates the interpolator of the points `(x[i], y[i])` with the given
+    /// slopes, where `x` is strictly increasing.
+    pub fn hermite(x: &[f32], y: &[f32], slopes: &[f32], extrapolation: Extrapolation) -> Self {
+        assert!(x.len() == y.len(), "x and y must have the same length");
+        assert!(
+            x.len() == slopes.len(),
+            "x and slopes must have the same length"
+        );
+
+        return Self {
+            x: Breakpoints::new(x),
+            y: y.to_vec(),
+            slopes: slopes.to_vec(),
+            extrapolation,
+        };
+    }
+
+    /// Monotone piecewise cubic interpolation (PCHIP) after Fritsch and
+    /// Carlson, which doesn't overshoot between the points and preserves
+    /// monotone data.
+    pub fn pchip(x: &[f32], y: &[f32], extrapolation: Extrapolation) -> Self {
+        assert!(x.len() == y.len(), "x and y must have the same length");
+
+        let breakpoints = Breakpoints::new(x);
+        let n = x.len();
+        let delta = secants(&breakpoints, y);
+        let mut slopes = vec![0.0; n];
+
+        if n == 2 {
+            slopes[0] = delta[0];
+            slopes[1] = delta[0];
+        } else {
+            // Weighted harmonic mean of the secants, 0 at extrema
+            for k in 1..n - 1 {
+                if delta[k - 1] * delta[k] > 0.0 {
+                    let h0 = breakpoints.width(k - 1);
+                    let h1 = breakpoints.width(k);
+                    let w0 = 2.0 * h1 + h0;
+                    let w1 = h1 + 2.0 * h0;
+                    slopes[k] = (w0 + w1) / (w0 / delta[k - 1] + w1 / delta[k]);
+                }
+            }
+
+            let h = |i: usize| breakpoints.width(i);
+            slopes[0] = Self::pchip_end_slope(h(0), h(1), delta[0], delta[1]);
+            slopes[n - 1] = Self::pchip_end_slope(h(n - 2), h(n - 3), delta[n - 2], delta[n - 3]);
+        }
+
+        return Self {
+            x: breakpoints,
+            y: y.to_vec(),
+            slopes,
+            extrapolation,
+        };
+    }
+
+    /// One-sided three-point slope estimate, limited to preserve the shape.
+    fn pchip_end_slope(h0: f32, h1: f32, delta0: f32, delta1: f32) -> f32 {
+        let slope = ((2.0 * h0 + h1) * delta0 - h0 * delta1) / (h0 + h1);
+
+        if slope * delta0 <= 0.0 {
+            return 0.0;
+        }
+        if delta0 * delta1 <= 0.0 && slope.abs() > (3.0 * delta0).abs() {
+            return 3.0 * delta0;
+        }
+        return slope;
+    }
+
+    /// Natural cubic spline, i.e. twice continuously differentiable with
+    /// zero curvature at both ends.
+    pub fn natural_spline(x: &[f32], y: &[f32], extrapolation: Extrapolation) -> Self {
+        assert!(x.len() == y.len(), "x and y must have the same length");
+
+        let breakpoints = Breakpoints::new(x);
+        let n = x.len();
+        let delta = secants(&breakpoints, y);
+
+        // Continuity of the curvature in the slopes d, which gives
+        // h[i] * d[i-1] + 2 * (h[i-1] + h[i]) * d[i] + h[i-1] * d[i+1]
+        //   = 3 * (h[i] * delta[i-1] + h[i-1] * delta[i])
+        let mut lower = vec![0.0; n - 1];
+        let mut diagonal = vec![0.0; n];
+        let mut upper = vec![0.0; n - 1];
+        let mut rhs = Matrix::zeros((n, 1));
+
+        diagonal[0] = 2.0;
+        upper[0] = 1.0;
+        rhs[(0, 0)] = 3.0 * delta[0];
+        for i in 1..n - 1 {
+            let h0 = breakpoints.width(i - 1);
+            let h1 = breakpoints.width(i);
+            lower[i - 1] = h1;
+            diagonal[i] = 2.0 * (h0 + h1);
+            upper[i] = h0;
+            rhs[(i, 0)] = 3.0 * (h1 * delta[i - 1] + h0 * delta[i]);
+        }
+        lower[n - 2] = 1.0;
+        diagonal[n - 1] = 2.0;
+        rhs[(n - 1, 0)] = 3.0 * delta[n - 2];
+
+        let solution = TridiagonalMatrix::new(lower, diagonal, upper).solve(&rhs);
+        let slopes = (0..n).map(|i| solution[(i, 0)]).collect();
+
+        return Self {
+            x: breakpoints,
+            y: y.to_vec(),
+            slopes,
+            extrapolation,
+        };
+    }
+
+    /// Derivative of the interpolant at `x`, following the extrapolation
+    /// policy outside of the breakpoints.
+    pub fn derivative(&self, x: f32) -> f32 {
+        let outside = x < self.x.first() || x > self.x.last();
+        if outside {
+            let end = if x < self.x.first() {
+                0
+            } else {
+                self.y.len() - 1
+            };
+            return match self.extrapolation {
+                Extrapolation::Nan => f32::NAN,
+                Extrapolation::Clamp => 0.0,
+                Extrapolation::Linear => self.slopes[end],
+            };
+        }
+
+        let i = self.x.segment(x);
+        let h = self.x.width(i);
+        let t = (x - self.x.points[i]) / h;
+
+        // Derivatives of the Hermite basis functions
+        let dh00 = 6.0 * t * t - 6.0 * t;
+        let dh10 = 3.0 * t * t - 4.0 * t + 1.0;
+        let dh01 = -dh00;
+        let dh11 = 3.0 * t * t - 2.0 * t;
+
+        return (dh00 * self.y[i] + dh01 * self.y[i + 1]) / h
+            + dh10 * self.slopes[i]
+            + dh11 * self.slopes[i + 1];
+    }
+}
+
+impl Interpolator for CubicInterpolator {
+    fn evaluate(&self, x: f32) -> f32 {
+        let outside = x < self.x.first() || x > self.x.last();
+        if outside {
+            let (end, x_end) = if x < self.x.first() {
+                (0, self.x.first())
+            } else {
+                (self.y.len() - 1, self.x.last())
+            };
+            return match self.extrapolation {
+                Extrapolation::Nan => f32::NAN,
+                Extrapolation::Clamp => self.y[end],
+                Extrapolation::Linear => self.y[end] + self.slopes[end] * (x - x_end),
+            };
+        }
+
+        let i = self.x.segment(x);
+        let h = self.x.width(i);
+        let t = (x - self.x.points[i]) / h;
+
+        // Hermite basis functions
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+        let h10 = t3 - 2.0 * t2 + t;
+        let h01 = 1.0 - h00;
+        let h11 = t3 - t2;
+
+        return h00 * self.y[i]
+            + h01 * self.y[i + 1]
+            + h * (h10 * self.slopes[i] + h11 * self.slopes[i + 1]);
+    }
+}
@@ -0,0 +1,50 @@
+use alloc::vec::Vec;
+
+use super::{Breakpoints, Extrapolation, Interpolator};
+
+/// Piecewise linear interpolation between the breakpoints.
+#[derive(Debug, Clone)]
+pub struct LinearInterpolator {
+    x: Breakpoints,
+    y: Vec<f32>,
+    pub extrapolation: Extrapolation,
+}
+
+impl LinearInterpolator {
+    /// Creates the interpolator of the points `(x[i], y[i])`, where `x` is
+    /// strictly increasing.
+    pub fn new(x: &[f32], y: &[f32], extrapolation: Extrapolation) -> Self {
+        assert!(x.len() == y.len(), "x and y must have the same length");
+
+        return Self {
+            x: Breakpoints::new(x),
+            y: y.to_vec(),
+            extrapolation,
+        };
+    }
+}
+
+impl Interpolator for LinearInterpolator {
+    fn evaluate(&self, x: f32) -> f32 {
+        let outside = x < self.x.first() || x > self.x.last();
+        if outside {
+            match self.extrapolation {
+                Extrapolation::Nan => return f32::NAN,
+                Extrapolation::Clamp => {
+                    let clamped = if x < self.x.first() {
+                        0
+                    } else {
+                        self.y.len() - 1
+                    };
+                    return self.y[clamped];
+                }
+                // The end segments simply continue
+                Extrapolation::Linear => (),
+            }
+        }
+
+        let i = self.x.segment(x);
+        let t = (x - self.x.points[i]) / self.x.width(i);
+        return self.y[i] + t * (self.y[i + 1] - self.y[i]);
+    }
+}
@@ -0,0 +1,103 @@
+use alloc::vec::Vec;
+use core::cell::Cell;
+
+use crate::matrix::Matrix;
+
+mod bilinear;
+mod cubic;
+mod linear;
+
+pub use bilinear::BilinearTable;
+pub use cubic::CubicInterpolator;
+pub use linear::LinearInterpolator;
+
+/// Behavior for queries outside of the breakpoint range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Extrapolation {
+    /// Holds the value at the nearest breakpoint.
+    Clamp,
+    /// Continues linearly with the slope at the nearest breakpoint.
+    Linear,
+    /// Returns NaN.
+    Nan,
+}
+
+/// One-dimensional interpolation of tabulated data.
+pub trait Interpolator {
+    fn evaluate(&self, x: f32) -> f32;
+
+    /// Evaluates the interpolator at each element of `x`.
+    ///
+    /// Monotone queries, e.g. a sorted column vector, benefit from the index
+    /// cache.
+    fn evaluate_matrix(&self, x: &Matrix) -> Matrix {
+        let mut result = Matrix::zeros(x.shape());
+        for m in 0..x.m {
+            for n in 0..x.n {
+                result[(m, n)] = self.evaluate(x[(m, n)]);
+            }
+        }
+        return result;
+    }
+}
+
+/// Strictly increasing breakpoints with a cache of the last segment.
+///
+/// Consecutive queries usually fall into the same or the next segment, which
+/// is checked before falling back to a binary search.
+#[derive(Debug, Clone)]
+struct Breakpoints {
+    points: Vec<f32>,
+    cache: Cell<usize>,
+}
+
+impl Breakpoints {
+    fn new(points: &[f32]) -> Self {
+        assert!(points.len() >= 2, "At least 2 breakpoints are required");
+        assert!(
+            points.windows(2).all(|w| w[0] < w[1]),
+            "Breakpoints must be strictly increasing"
+        );
+
+        return Self {
+            points: points.to_vec(),
+            cache: Cell::new(0),
+        };
+    }
+
+    fn len(&self) -> usize {
+        return self.points.len();
+    }
+
+    fn first(&self) -> f32 {
+        return self.points[0];
+    }
+
+    fn last(&self) -> f32 {
+        return self.points[self.points.len() - 1];
+    }
+
+    /// Width of the segment `i`.
+    fn width(&self, i: usize) -> f32 {
+        return self.points[i + 1] - self.points[i];
+    }
+
+    /// Returns the index `i` of the segment `[p[i], p[i + 1])` containing
+    /// `x`, clamped to the first and the last segment.
+    fn segment(&self, x: f32) -> usize {
+        let p = &self.points;
+        let last = p.len() - 2;
+        let cached = self.cache.get();
+
+        let i = if x >= p[cached] && (cached == last || x < p[cached + 1]) {
+            cached
+        } else if cached < last && x >= p[cached + 1] && (cached + 1 == last || x < p[cached + 2]) {
+            cached + 1
+        } else {
+            p.partition_point(|&point| point <= x).clamp(1, last + 1) - 1
+        };
+
+        self.cache.set(i);
+        return i;
+    }
+}
@@ -1,12 +1,21 @@
 #![feature(allocator_api)]
 #![feature(const_float_bits_conv)]
 #![feature(asm_const)]
+#![feature(arm_target_feature)]
 #![no_std]
 #![no_main]
 #![allow(unused)]
 
 extern crate alloc;
+mod angle;
+mod asm_math;
+mod control;
+mod filters;
+mod interpolation;
 mod matrix;
+mod polynomial;
+mod quaternion;
+mod trig;
 
 use alloc::vec::Vec;
 use core::alloc::{GlobalAlloc, Layout};
@@ -0,0 +1,299 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops;
+
+use super::{LinearOperator, Matrix};
+
+/// Square tridiagonal matrix stored as its three diagonals.
+#[derive(Debug, Clone)]
+pub struct TridiagonalMatrix {
+    /// Sub-diagonal `A[i + 1][i]`, length `n - 1`.
+    pub lower: Vec<f32>,
+    /// Main diagonal `A[i][i]`, length `n`.
+    pub diagonal: Vec<f32>,
+    /// Super-diagonal `A[i][i + 1]`, length `n - 1`.
+    pub upper: Vec<f32>,
+}
+
+impl TridiagonalMatrix {
+    pub fn new(lower: Vec<f32>, diagonal: Vec<f32>, upper: Vec<f32>) -> Self {
+        assert!(!diagonal.is_empty(), "Tridiagonal matrix can't be empty");
+        assert!(
+            lower.len() + 1 == diagonal.len() && upper.len() + 1 == diagonal.len(),
+            "Off-diagonals must be one element shorter than the diagonal"
+        );
+        return Self {
+            lower,
+            diagonal,
+            upper,
+        };
+    }
+
+    /// Extracts the three central diagonals of a quadratic matrix. Elements
+    /// outside of them are ignored.
+    pub fn from_dense(matrix: &Matrix) -> Self {
+        assert!(matrix.is_quadratic(), "Matrix must be quadratic");
+
+        let n = matrix.m;
+        let lower = (1..n).map(|i| matrix[(i, i - 1)]).collect();
+        let diagonal = (0..n).map(|i| matrix[(i, i)]).collect();
+        let upper = (1..n).map(|i| matrix[(i - 1, i)]).collect();
+
+        return Self::new(lower, diagonal, upper);
+    }
+
+    pub fn to_dense(&self) -> Matrix {
+        let n = self.len();
+        let mut result = Matrix::zeros((n, n));
+
+        for i in 0..n {
+            result[(i, i)] = self.diagonal[i];
+            if i + 1 < n {
+                result[(i + 1, i)] = self.lower[i];
+                result[(i, i + 1)] = self.upper[i];
+            }
+        }
+
+        return result;
+    }
+
+    pub fn len(&self) -> usize {
+        return self.diagonal.len();
+    }
+
+    /// Solves `A * x = rhs` with the Thomas algorithm in O(n).
+    ///
+    /// No pivoting is done, so `A` should be diagonally dominant or
+    /// symmetric positive definite.
+    pub fn solve(&self, rhs: &Matrix) -> Matrix {
+        let n = self.len();
+        assert!(
+            rhs.is_column_vector() && rhs.len() == n,
+            "Right-hand side must be a column vector of length {}",
+            n
+        );
+
+        let mut upper = vec![0.0; n];
+        let mut x = rhs.data.clone();
+
+        // Forward sweep
+        let mut pivot = self.diagonal[0];
+        assert!(pivot != 0.0, "Zero pivot in tridiagonal solve");
+        if n > 1 {
+            upper[0] = self.upper[0] / pivot;
+        }
+        x[0] /= pivot;
+
+        for i in 1..n {
+            pivot = self.diagonal[i] - self.lower[i - 1] * upper[i - 1];
+            assert!(pivot != 0.0, "Zero pivot in tridiagonal solve");
+            if i + 1 < n {
+                upper[i] = self.upper[i] / pivot;
+            }
+            x[i] = (x[i] - self.lower[i - 1] * x[i - 1]) / pivot;
+        }
+
+        // Back substitution
+        for i in (0..n - 1).rev() {
+            x[i] -= upper[i] * x[i + 1];
+        }
+
+        return Matrix::from(x, (n, 1));
+    }
+}
+
+impl LinearOperator for TridiagonalMatrix {
+    fn size(&self) -> usize {
+        return self.len();
+    }
+
+    fn diagonal(&self, i: usize) -> f32 {
+        return self.diagonal[i];
+    }
+
+    fn row_dot(&self, i: usize, x: &[f32]) -> f32 {
+        let mut result = self.diagonal[i] * x[i];
+        if i > 0 {
+            result += self.lower[i - 1] * x[i - 1];
+        }
+        if i + 1 < self.len() {
+            result += self.upper[i] * x[i + 1];
+        }
+        return result;
+    }
+}
+
+/// Square band matrix with `kl` sub- and `ku` super-diagonals.
+///
+/// Row `i` stores the elements `A[i][i - kl..=i + ku]`, so the matrix takes
+/// `n * (kl + ku + 1)` elements instead of `n * n`.
+#[derive(Debug, Clone)]
+pub struct BandedMatrix {
+    pub n: usize,
+    pub kl: usize,
+    pub ku: usize,
+    data: Vec<f32>,
+}
+
+impl ops::Index<(usize, usize)> for BandedMatrix {
+    type Output = f32;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        assert!(
+            (index.0 < self.n) && (index.1 < self.n),
+            "Matrix index is out-of-bounds."
+        );
+        if !self.in_band(index.0, index.1) {
+            return &0.0;
+        }
+        return &self.data[self.offset(index.0, index.1)];
+    }
+}
+
+impl ops::IndexMut<(usize, usize)> for BandedMatrix {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        assert!(
+            (index.0 < self.n) && (index.1 < self.n),
+            "Matrix index is out-of-bounds."
+        );
+        assert!(
+            self.in_band(index.0, index.1),
+            "Matrix index is outside of the band."
+        );
+        let offset = self.offset(index.0, index.1);
+        return &mut self.data[offset];
+    }
+}
+
+impl BandedMatrix {
+    pub fn zeros(n: usize, kl: usize, ku: usize) -> Self {
+        return Self {
+            n,
+            kl,
+            ku,
+            data: vec![0.0; n * (kl + ku + 1)],
+        };
+    }
+
+    /// Copies the band of a quadratic matrix. Elements outside of it are
+    /// ignored.
+    pub fn from_dense(matrix: &Matrix, kl: usize, ku: usize) -> Self {
+        assert!(matrix.is_quadratic(), "Matrix must be quadratic");
+
+        let mut result = Self::zeros(matrix.m, kl, ku);
+        for i in 0..result.n {
+            for j in result.band_columns(i) {
+                result[(i, j)] = matrix[(i, j)];
+            }
+        }
+
+        return result;
+    }
+
+    pub fn to_dense(&self) -> Matrix {
+        let mut result = Matrix::zeros((self.n, self.n));
+
+        for i in 0..self.n {
+            for j in self.band_columns(i) {
+                result[(i, j)] = self[(i, j)];
+            }
+        }
+
+        return result;
+    }
+
+    fn in_band(&self, i: usize, j: usize) -> bool {
+        return j + self.kl >= i && j <= i + self.ku;
+    }
+
+    fn offset(&self, i: usize, j: usize) -> usize {
+        return i * (self.kl + self.ku + 1) + j + self.kl - i;
+    }
+
+    /// Column range of the band in row `i`.
+    fn band_columns(&self, i: usize) -> ops::Range<usize> {
+        return i.saturating_sub(self.kl)..usize::min(self.n, i + self.ku + 1);
+    }
+
+    /// Computes the LU decomposition in O(n * kl * ku).
+    ///
+    /// No pivoting is done, so the fill-in stays inside the band. `A` should
+    /// be diagonally dominant or symmetric positive definite.
+    pub fn lu(&self) -> BandedLu {
+        let mut lu = self.clone();
+
+        for k in 0..self.n {
+            let pivot = lu[(k, k)];
+            assert!(pivot != 0.0, "Zero pivot in banded LU decomposition");
+
+            for i in k + 1..usize::min(self.n, k + self.kl + 1) {
+                let factor = lu[(i, k)] / pivot;
+                lu[(i, k)] = factor;
+                for j in k + 1..usize::min(self.n, k + self.ku + 1) {
+                    let value = lu[(k, j)];
+                    lu[(i, j)] -= factor * value;
+                }
+            }
+        }
+
+        return BandedLu { lu };
+    }
+
+    /// Solves `A * x = rhs` via the banded LU decomposition.
+    pub fn solve(&self, rhs: &Matrix) -> Matrix {
+        return self.lu().solve(rhs);
+    }
+}
+
+impl LinearOperator for BandedMatrix {
+    fn size(&self) -> usize {
+        return self.n;
+    }
+
+    fn diagonal(&self, i: usize) -> f32 {
+        return self[(i, i)];
+    }
+
+    fn row_dot(&self, i: usize, x: &[f32]) -> f32 {
+        return self.band_columns(i).map(|j| self[(i, j)] * x[j]).sum();
+    }
+}
+
+/// LU factors of a `BandedMatrix`, with the unit lower triangle `L` and the
+/// upper triangle `U` sharing the band storage.
+#[derive(Debug, Clone)]
+pub struct BandedLu {
+    lu: BandedMatrix,
+}
+
+impl BandedLu {
+    /// Solves `A * x = rhs` by forward and back substitution.
+    pub fn solve(&self, rhs: &Matrix) -> Matrix {
+        let lu = &self.lu;
+        let n = lu.n;
+        assert!(
+            rhs.is_column_vector() && rhs.len() == n,
+            "Right-hand side must be a column vector of length {}",
+            n
+        );
+
+        let mut x = rhs.data.clone();
+
+        // L * y = rhs
+        for i in 0..n {
+            for j in i.saturating_sub(lu.kl)..i {
+                x[i] -= lu[(i, j)] * x[j];
+            }
+        }
+
+        // U * x = y
+        for i in (0..n).rev() {
+            for j in i + 1..usize::min(n, i + lu.ku + 1) {
+                x[i] -= lu[(i, j)] * x[j];
+            }
+            x[i] /= lu[(i, i)];
+        }
+
+        return Matrix::from(x, (n, 1));
+    }
+}
@@ -0,0 +1,171 @@
+use libm::sqrtf;
+
+use super::Matrix;
+
+impl Matrix {
+    /// Replaces the symmetric positive definite matrix inplace by its lower
+    /// Cholesky factor `L` with `self = L * L^T`.
+    ///
+    /// Only the lower triangle is read. Returns `false` (leaving the matrix
+    /// in an unspecified state) if the matrix isn't positive definite.
+    pub fn cholesky_ip(&mut self) -> bool {
+        assert!(
+            self.is_quadratic(),
+            "Matrix must be quadratic for Cholesky decomposition"
+        );
+
+        let size = self.m;
+        for j in 0..size {
+            let diagonal = self[(j, j)] - (0..j).map(|k| self[(j, k)] * self[(j, k)]).sum::<f32>();
+            if diagonal.is_nan() || diagonal <= 0.0 {
+                return false;
+            }
+            let diagonal = sqrtf(diagonal);
+            self[(j, j)] = diagonal;
+
+            for i in j + 1..size {
+                let value = self[(i, j)] - (0..j).map(|k| self[(i, k)] * self[(j, k)]).sum::<f32>();
+                self[(i, j)] = value / diagonal;
+            }
+
+            // Clear the upper triangle
+            for i in 0..j {
+                self[(i, j)] = 0.0;
+            }
+        }
+
+        return true;
+    }
+
+    /// Returns the lower Cholesky factor `L` with `self = L * L^T`, or `None`
+    /// if the matrix isn't positive definite.
+    pub fn cholesky(&self) -> Option<Matrix> {
+        let mut result = self.clone();
+        if !result.cholesky_ip() {
+            return None;
+        }
+
+        return Some(result);
+    }
+
+    /// Solves `L * L^T * X = rhs` inplace, where `self` is the lower Cholesky
+    /// factor `L`.
+    pub fn cholesky_solve_ip(&self, rhs: &mut Matrix) {
+        assert!(
+            self.is_quadratic() && self.m == rhs.m,
+            "Can't solve {}x{} system for {}x{} matrix.",
+            self.m,
+            self.n,
+            rhs.m,
+            rhs.n,
+        );
+
+        let size = self.m;
+        for column in 0..rhs.n {
+            // L * y = rhs
+            for i in 0..size {
+                let known: f32 = (0..i).map(|k| self[(i, k)] * rhs[(k, column)]).sum();
+                rhs[(i, column)] = (rhs[(i, column)] - known) / self[(i, i)];
+            }
+
+            // L^T * x = y
+            for i in (0..size).rev() {
+                let known: f32 = (i + 1..size).map(|k| self[(k, i)] * rhs[(k, column)]).sum();
+                rhs[(i, column)] = (rhs[(i, column)] - known) / self[(i, i)];
+            }
+        }
+    }
+
+    /// Returns the inverse via Gauss-Jordan elimination with partial
+    /// pivoting, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Matrix> {
+        assert!(
+            self.is_quadratic(),
+            "Matrix must be quadratic for inversion"
+        );
+
+        let size = self.m;
+        let mut lhs = self.clone();
+        let mut result = Matrix::identity(size);
+
+        for k in 0..size {
+            // Use the largest remaining element of the column as pivot
+            let pivot_row = (k..size)
+                .max_by(|&a, &b| lhs[(a, k)].abs().total_cmp(&lhs[(b, k)].abs()))
+                .unwrap();
+            let pivot = lhs[(pivot_row, k)];
+            if pivot == 0.0 || pivot.is_nan() {
+                return None;
+            }
+
+            if pivot_row != k {
+                for n in 0..size {
+                    lhs.data.swap(k * size + n, pivot_row * size + n);
+                    result.data.swap(k * size + n, pivot_row * size + n);
+                }
+            }
+
+            for n in 0..size {
+                lhs[(k, n)] /= pivot;
+                result[(k, n)] /= pivot;
+            }
+
+            for m in 0..size {
+                let factor = lhs[(m, k)];
+                if m == k || factor == 0.0 {
+                    continue;
+                }
+                for n in 0..size {
+                    lhs[(m, n)] -= factor * lhs[(k, n)];
+                    result[(m, n)] -= factor * result[(k, n)];
+                }
+            }
+        }
+
+        return Some(result);
+    }
+
+    /// Returns the rank via Gaussian elimination with partial pivoting.
+    ///
+    /// Pivots below `1e-5` times the largest absolute element count as
+    /// zero, which suits matrices with f32 rounding errors.
+    pub fn rank(&self) -> usize {
+        let max = self.data.iter().fold(0.0f32, |max, e| max.max(e.abs()));
+        return self.rank_with_tolerance(1e-5 * max);
+    }
+
+    /// Returns the rank, counting pivots with an absolute value up to
+    /// `tolerance` as zero.
+    pub fn rank_with_tolerance(&self, tolerance: f32) -> usize {
+        let mut echelon = self.clone();
+        let mut rank = 0;
+
+        for n in 0..self.n {
+            if rank == self.m {
+                break;
+            }
+
+            let pivot_row = (rank..self.m)
+                .max_by(|&a, &b| echelon[(a, n)].abs().total_cmp(&echelon[(b, n)].abs()))
+                .unwrap();
+            let pivot = echelon[(pivot_row, n)];
+            if pivot.abs() <= tolerance {
+                continue;
+            }
+
+            for o in 0..self.n {
+                echelon.data.swap(rank * self.n + o, pivot_row * self.n + o);
+            }
+
+            for m in rank + 1..self.m {
+                let factor = echelon[(m, n)] / pivot;
+                for o in n..self.n {
+                    echelon[(m, o)] -= factor * echelon[(rank, o)];
+                }
+            }
+            rank += 1;
+        }
+
+        return rank;
+    }
+}
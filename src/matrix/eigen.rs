@@ -0,0 +1,271 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use libm::sqrtf;
+
+use super::Matrix;
+
+/// Iterations per eigenvalue after which the QR iteration gives up.
+const QR_MAX_ITERATIONS: usize = 30;
+
+impl Matrix {
+    /// Reduces the matrix inplace to upper Hessenberg form by a similarity
+    /// transform with Householder reflectors, which preserves the
+    /// eigenvalues.
+    pub fn hessenberg_ip(&mut self) {
+        assert!(self.is_quadratic(), "Matrix isn't quadratic");
+
+        for k in 0..self.m.saturating_sub(2) {
+            let mut v = Matrix::zeros((self.m - k - 1, 1));
+            for i in 0..v.m {
+                v.data[i] = self[(k + 1 + i, k)];
+            }
+
+            let alpha = sqrtf(v.T().dot(&v));
+            if alpha == 0.0 {
+                continue;
+            }
+            v.data[0] += if v.data[0] < 0.0 { -alpha } else { alpha };
+
+            self.apply_householder_left(&v, k + 1);
+            self.apply_householder_right(&v, k + 1);
+
+            // Clear the rounding residue below the subdiagonal
+            for i in k + 2..self.m {
+                self[(i, k)] = 0.0;
+            }
+        }
+    }
+
+    pub fn hessenberg(&self) -> Matrix {
+        let mut result = self.clone();
+        result.hessenberg_ip();
+        return result;
+    }
+
+    /// Scales rows and columns inplace by powers of 2 with a similarity
+    /// transform, so that their norms are of similar magnitude. This
+    /// reduces the rounding errors of the eigenvalue computation.
+    fn balance_ip(&mut self) {
+        const RADIX: f32 = 2.0;
+        let mut done = false;
+
+        while !done {
+            done = true;
+            for i in 0..self.m {
+                let mut c = 0.0;
+                let mut r = 0.0;
+                for j in 0..self.m {
+                    if j != i {
+                        c += self[(j, i)].abs();
+                        r += self[(i, j)].abs();
+                    }
+                }
+                if c == 0.0 || r == 0.0 {
+                    continue;
+                }
+
+                let s = c + r;
+                let mut f = 1.0;
+                while c < r / RADIX {
+                    f *= RADIX;
+                    c *= RADIX * RADIX;
+                }
+                while c > r * RADIX {
+                    f /= RADIX;
+                    c /= RADIX * RADIX;
+                }
+
+                if (c + r) / f < 0.95 * s {
+                    done = false;
+                    for j in 0..self.m {
+                        self[(i, j)] /= f;
+                        self[(j, i)] *= f;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns all eigenvalues as `(real, imaginary)` pairs, with complex
+    /// conjugate pairs next to each other, or `None` if the QR iteration
+    /// doesn't converge.
+    ///
+    /// The matrix is balanced and reduced to Hessenberg form, followed by
+    /// the Francis double shift QR iteration.
+    pub fn eigenvalues(&self) -> Option<Vec<(f32, f32)>> {
+        assert!(self.is_quadratic(), "Matrix isn't quadratic");
+
+        let mut a = self.clone();
+        a.balance_ip();
+        a.hessenberg_ip();
+
+        let mut result = vec![(0.0, 0.0); a.m];
+        let norm: f32 = a.data.iter().map(|e| e.abs()).sum();
+
+        // Signed indices, as the deflation walks below 0
+        let at = |a: &Matrix, i: isize, j: isize| a[(i as usize, j as usize)];
+        let mut nn = a.m as isize - 1;
+        // Accumulated exceptional shifts
+        let mut t = 0.0;
+
+        while nn >= 0 {
+            let mut iterations = 0;
+            loop {
+                // Find a negligible subdiagonal element to split the matrix
+                let mut l = nn;
+                while l >= 1 {
+                    let mut s = at(&a, l - 1, l - 1).abs() + at(&a, l, l).abs();
+                    if s == 0.0 {
+                        s = norm;
+                    }
+                    if at(&a, l, l - 1).abs() + s == s {
+                        a[(l as usize, l as usize - 1)] = 0.0;
+                        break;
+                    }
+                    l -= 1;
+                }
+
+                let mut x = at(&a, nn, nn);
+                if l == nn {
+                    // One real root
+                    result[nn as usize] = (x + t, 0.0);
+                    nn -= 1;
+                    break;
+                }
+
+                let mut y = at(&a, nn - 1, nn - 1);
+                let mut w = at(&a, nn, nn - 1) * at(&a, nn - 1, nn);
+                if l == nn - 1 {
+                    // Two roots of the trailing 2x2 block
+                    let p = 0.5 * (y - x);
+                    let q = p * p + w;
+                    let z = sqrtf(q.abs());
+                    x += t;
+                    if q >= 0.0 {
+                        let z = p + if p < 0.0 { -z } else { z };
+                        let second = if z != 0.0 { x - w / z } else { x + z };
+                        result[nn as usize - 1] = (x + z, 0.0);
+                        result[nn as usize] = (second, 0.0);
+                    } else {
+                        result[nn as usize - 1] = (x + p, z);
+                        result[nn as usize] = (x + p, -z);
+                    }
+                    nn -= 2;
+                    break;
+                }
+
+                if iterations == QR_MAX_ITERATIONS {
+                    return None;
+                }
+                if iterations == 10 || iterations == 20 {
+                    // Exceptional shift to break cycles
+                    t += x;
+                    for i in 0..=nn as usize {
+                        a[(i, i)] -= x;
+                    }
+                    let s = at(&a, nn, nn - 1).abs() + at(&a, nn - 1, nn - 2).abs();
+                    x = 0.75 * s;
+                    y = x;
+                    w = -0.4375 * s * s;
+                }
+                iterations += 1;
+
+                // Find two consecutive small subdiagonal elements
+                let (mut p, mut q, mut r);
+                let mut m = nn - 2;
+                loop {
+                    let z = at(&a, m, m);
+                    let rr = x - z;
+                    let ss = y - z;
+                    p = (rr * ss - w) / at(&a, m + 1, m) + at(&a, m, m + 1);
+                    q = at(&a, m + 1, m + 1) - z - rr - ss;
+                    r = at(&a, m + 2, m + 1);
+                    let s = p.abs() + q.abs() + r.abs();
+                    p /= s;
+                    q /= s;
+                    r /= s;
+                    if m == l {
+                        break;
+                    }
+                    let u = at(&a, m, m - 1).abs() * (q.abs() + r.abs());
+                    let v = p.abs()
+                        * (at(&a, m - 1, m - 1).abs() + z.abs() + at(&a, m + 1, m + 1).abs());
+                    if u + v == v {
+                        break;
+                    }
+                    m -= 1;
+                }
+
+                for i in m + 2..=nn {
+                    a[(i as usize, i as usize - 2)] = 0.0;
+                    if i != m + 2 {
+                        a[(i as usize, i as usize - 3)] = 0.0;
+                    }
+                }
+
+                // Double shift QR step on the rows and columns l..=nn
+                for k in m..nn {
+                    if k != m {
+                        p = at(&a, k, k - 1);
+                        q = at(&a, k + 1, k - 1);
+                        r = if k + 1 != nn {
+                            at(&a, k + 2, k - 1)
+                        } else {
+                            0.0
+                        };
+                        x = p.abs() + q.abs() + r.abs();
+                        if x != 0.0 {
+                            p /= x;
+                            q /= x;
+                            r /= x;
+                        }
+                    }
+
+                    let s = sqrtf(p * p + q * q + r * r);
+                    let s = if p < 0.0 { -s } else { s };
+                    if s == 0.0 {
+                        continue;
+                    }
+
+                    let row = k as usize;
+                    if k == m {
+                        if l != m {
+                            a[(row, row - 1)] = -a[(row, row - 1)];
+                        }
+                    } else {
+                        a[(row, row - 1)] = -s * x;
+                    }
+                    p += s;
+                    x = p / s;
+                    y = q / s;
+                    let z = r / s;
+                    q /= p;
+                    r /= p;
+
+                    for j in row..=nn as usize {
+                        let mut p = a[(row, j)] + q * a[(row + 1, j)];
+                        if k + 1 != nn {
+                            p += r * a[(row + 2, j)];
+                            a[(row + 2, j)] -= p * z;
+                        }
+                        a[(row + 1, j)] -= p * y;
+                        a[(row, j)] -= p * x;
+                    }
+
+                    let last = if nn < k + 3 { nn } else { k + 3 };
+                    for i in l as usize..=last as usize {
+                        let mut p = x * a[(i, row)] + y * a[(i, row + 1)];
+                        if k + 1 != nn {
+                            p += z * a[(i, row + 2)];
+                            a[(i, row + 2)] -= p * r;
+                        }
+                        a[(i, row + 1)] -= p * q;
+                        a[(i, row)] -= p;
+                    }
+                }
+            }
+        }
+
+        return Some(result);
+    }
+}
@@ -0,0 +1,307 @@
+use alloc::vec;
+use libm::sqrtf;
+
+use super::Matrix;
+
+/// Square operator `y = A * x` as seen by the iterative solvers.
+///
+/// Implement this for any (sparse) storage to make it usable with the
+/// conjugate gradient, Jacobi, Gauss-Seidel and SOR solvers.
+pub trait LinearOperator {
+    /// Number of rows (and columns) of the operator.
+    fn size(&self) -> usize;
+
+    /// Returns the diagonal element `A[i][i]`.
+    fn diagonal(&self, i: usize) -> f32;
+
+    /// Returns the dot product of the i-th row of `A` with `x`.
+    fn row_dot(&self, i: usize, x: &[f32]) -> f32;
+
+    /// Computes `y = A * x`.
+    fn apply(&self, x: &[f32], y: &mut [f32]) {
+        for (i, e) in y.iter_mut().enumerate() {
+            *e = self.row_dot(i, x);
+        }
+    }
+}
+
+impl LinearOperator for Matrix {
+    fn size(&self) -> usize {
+        assert!(self.is_quadratic(), "Linear operator must be quadratic");
+        return self.m;
+    }
+
+    fn diagonal(&self, i: usize) -> f32 {
+        return self[(i, i)];
+    }
+
+    fn row_dot(&self, i: usize, x: &[f32]) -> f32 {
+        let row = &self.data[i * self.n..(i + 1) * self.n];
+        return row.iter().zip(x.iter()).map(|(a, x)| a * x).sum();
+    }
+}
+
+/// Stopping criteria of an iterative solver.
+#[derive(Debug, Clone, Copy)]
+pub struct IterationLimits {
+    pub max_iterations: usize,
+    /// Relative residual `|b - A * x| / |b|` at which the solver stops.
+    pub tolerance: f32,
+}
+
+impl Default for IterationLimits {
+    fn default() -> Self {
+        return Self {
+            max_iterations: 100,
+            tolerance: 1e-6,
+        };
+    }
+}
+
+/// Outcome of an iterative solver run.
+#[derive(Debug, Clone, Copy)]
+pub struct ConvergenceReport {
+    pub iterations: usize,
+    /// Relative residual `|b - A * x| / |b|` of the returned solution.
+    pub residual: f32,
+    pub converged: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preconditioner {
+    None,
+    /// Scales the residual by the inverse diagonal of `A`.
+    Jacobi,
+}
+
+fn norm(v: &[f32]) -> f32 {
+    return sqrtf(v.iter().map(|e| e * e).sum());
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    return a.iter().zip(b.iter()).map(|(a, b)| a * b).sum();
+}
+
+fn assert_system<A: LinearOperator + ?Sized>(a: &A, b: &Matrix, x: &Matrix) {
+    assert!(
+        b.is_column_vector() && b.len() == a.size(),
+        "Right-hand side must be a column vector of length {}",
+        a.size()
+    );
+    assert!(
+        x.is_column_vector() && x.len() == a.size(),
+        "Solution must be a column vector of length {}",
+        a.size()
+    );
+}
+
+/// Norm `|b|` the residuals are relative to, or 1 for `b = 0` so they stay
+/// absolute then.
+fn residual_scale(b: &[f32]) -> f32 {
+    let b_norm = norm(b);
+    if b_norm == 0.0 {
+        return 1.0;
+    }
+    return b_norm;
+}
+
+/// Relative residual `|b - A * x| / b_norm`, using `r` as scratch space.
+fn relative_residual<A: LinearOperator + ?Sized>(
+    a: &A,
+    b: &[f32],
+    b_norm: f32,
+    x: &[f32],
+    r: &mut [f32],
+) -> f32 {
+    a.apply(x, r);
+    for i in 0..r.len() {
+        r[i] = b[i] - r[i];
+    }
+
+    return norm(r) / b_norm;
+}
+
+/// Solves the symmetric positive definite system `A * x = b` with the
+/// (preconditioned) conjugate gradient method.
+///
+/// `x` holds the initial guess and is overwritten with the solution. Stops
+/// early and reports no convergence if `A` turns out not to be positive
+/// definite.
+pub fn conjugate_gradient<A: LinearOperator + ?Sized>(
+    a: &A,
+    b: &Matrix,
+    x: &mut Matrix,
+    preconditioner: Preconditioner,
+    limits: IterationLimits,
+) -> ConvergenceReport {
+    assert_system(a, b, x);
+
+    let size = a.size();
+    let b = &b.data;
+    let x = &mut x.data;
+
+    let precondition = |r: &[f32], z: &mut [f32]| match preconditioner {
+        Preconditioner::None => z.copy_from_slice(r),
+        Preconditioner::Jacobi => {
+            for i in 0..size {
+                z[i] = r[i] / a.diagonal(i);
+            }
+        }
+    };
+
+    let b_norm = residual_scale(b);
+
+    let mut r = vec![0.0; size];
+    let mut z = vec![0.0; size];
+    let mut p = vec![0.0; size];
+    let mut ap = vec![0.0; size];
+
+    // r = b - A * x
+    a.apply(x, &mut r);
+    for i in 0..size {
+        r[i] = b[i] - r[i];
+    }
+    precondition(&r, &mut z);
+    p.copy_from_slice(&z);
+    let mut rz = dot(&r, &z);
+
+    let mut residual = norm(&r) / b_norm;
+    let mut iterations = 0;
+
+    while residual > limits.tolerance && iterations < limits.max_iterations {
+        a.apply(&p, &mut ap);
+        // Stop without touching x if A isn't positive definite along p
+        let curvature = dot(&p, &ap);
+        if curvature.is_nan() || curvature <= 0.0 {
+            break;
+        }
+        let alpha = rz / curvature;
+
+        for i in 0..size {
+            x[i] += alpha * p[i];
+            r[i] -= alpha * ap[i];
+        }
+
+        iterations += 1;
+        residual = norm(&r) / b_norm;
+        if residual <= limits.tolerance {
+            break;
+        }
+
+        precondition(&r, &mut z);
+        let rz_next = dot(&r, &z);
+        let beta = rz_next / rz;
+        rz = rz_next;
+
+        for i in 0..size {
+            p[i] = z[i] + beta * p[i];
+        }
+    }
+
+    return ConvergenceReport {
+        iterations,
+        residual,
+        converged: residual <= limits.tolerance,
+    };
+}
+
+/// Solves `A * x = b` with the Jacobi method. Converges for strictly
+/// diagonally dominant `A`.
+///
+/// `x` holds the initial guess and is overwritten with the solution.
+pub fn jacobi<A: LinearOperator + ?Sized>(
+    a: &A,
+    b: &Matrix,
+    x: &mut Matrix,
+    limits: IterationLimits,
+) -> ConvergenceReport {
+    assert_system(a, b, x);
+
+    let size = a.size();
+    let b = &b.data;
+    let x = &mut x.data;
+
+    let mut x_next = vec![0.0; size];
+    let mut scratch = vec![0.0; size];
+
+    let b_norm = residual_scale(b);
+    let mut residual = relative_residual(a, b, b_norm, x, &mut scratch);
+    let mut iterations = 0;
+
+    while residual > limits.tolerance && iterations < limits.max_iterations {
+        for i in 0..size {
+            let diagonal = a.diagonal(i);
+            let off_diagonal = a.row_dot(i, x) - diagonal * x[i];
+            x_next[i] = (b[i] - off_diagonal) / diagonal;
+        }
+        x.copy_from_slice(&x_next);
+
+        iterations += 1;
+        residual = relative_residual(a, b, b_norm, x, &mut scratch);
+    }
+
+    return ConvergenceReport {
+        iterations,
+        residual,
+        converged: residual <= limits.tolerance,
+    };
+}
+
+/// Solves `A * x = b` with the Gauss-Seidel method, i.e. SOR with `omega = 1`.
+///
+/// `x` holds the initial guess and is overwritten with the solution.
+pub fn gauss_seidel<A: LinearOperator + ?Sized>(
+    a: &A,
+    b: &Matrix,
+    x: &mut Matrix,
+    limits: IterationLimits,
+) -> ConvergenceReport {
+    return sor(a, b, x, 1.0, limits);
+}
+
+/// Solves `A * x = b` with successive over-relaxation. `omega` must lie in
+/// `(0, 2)`; values above 1 speed up convergence for many SPD systems.
+///
+/// `x` holds the initial guess and is overwritten with the solution.
+pub fn sor<A: LinearOperator + ?Sized>(
+    a: &A,
+    b: &Matrix,
+    x: &mut Matrix,
+    omega: f32,
+    limits: IterationLimits,
+) -> ConvergenceReport {
+    assert_system(a, b, x);
+    assert!(
+        omega > 0.0 && omega < 2.0,
+        "Relaxation factor must lie in (0, 2)"
+    );
+
+    let size = a.size();
+    let b = &b.data;
+    let x = &mut x.data;
+
+    let mut scratch = vec![0.0; size];
+
+    let b_norm = residual_scale(b);
+    let mut residual = relative_residual(a, b, b_norm, x, &mut scratch);
+    let mut iterations = 0;
+
+    while residual > limits.tolerance && iterations < limits.max_iterations {
+        // Sweep in place so updated entries are used immediately
+        for i in 0..size {
+            let diagonal = a.diagonal(i);
+            let off_diagonal = a.row_dot(i, x) - diagonal * x[i];
+            let gauss_seidel = (b[i] - off_diagonal) / diagonal;
+            x[i] += omega * (gauss_seidel - x[i]);
+        }
+
+        iterations += 1;
+        residual = relative_residual(a, b, b_norm, x, &mut scratch);
+    }
+
+    return ConvergenceReport {
+        iterations,
+        residual,
+        converged: residual <= limits.tolerance,
+    };
+}
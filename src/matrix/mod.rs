@@ -2,7 +2,10 @@ use alloc::string::String;
 use alloc::vec::Vec;
 use alloc::{format, vec};
 use core::{fmt, iter, ops};
-use libm::{cosf, sincosf, sinf};
+use libm::sqrtf;
+
+use crate::angle::Angle;
+use crate::trig::TrigBackend;
 
 #[derive(Debug, Clone)]
 pub struct Matrix {
@@ -148,20 +151,36 @@ impl Matrix {
         return result;
     }
 
-    pub fn rotation_2d(angle: f32) -> Self {
-        let (sin, cos) = sincosf(angle);
+    /// Rotation by `angle` in any unit, e.g. `Matrix::rotation_2d(Degrees(90.0))`
+    /// or radians as a bare `f32`.
+    pub fn rotation_2d<A: Angle>(angle: A) -> Self {
+        return Self::rotation_2d_sin_cos(angle.sin_cos());
+    }
+
+    /// `rotation_2d` with the trigonometric functions of the backend `T`.
+    pub fn rotation_2d_with<T: TrigBackend>(angle: f32) -> Self {
+        return Self::rotation_2d_sin_cos(T::sin_cos(angle));
+    }
+
+    fn rotation_2d_sin_cos((sin, cos): (f32, f32)) -> Self {
         return Matrix::new([[cos, -sin], [sin, cos]]);
     }
 
-    pub fn rotation_3d(yaw: f32, pitch: f32, roll: f32) -> Self {
-        // Precompute trigonometric values
-        let cos_yaw = cosf(yaw);
-        let sin_yaw = sinf(yaw);
-        let cos_pitch = cosf(pitch);
-        let sin_pitch = sinf(pitch);
-        let cos_roll = cosf(roll);
-        let sin_roll = sinf(roll);
+    /// Rotation `Rz(yaw) * Ry(pitch) * Rx(roll)` of angles in any unit.
+    pub fn rotation_3d<A: Angle>(yaw: A, pitch: A, roll: A) -> Self {
+        return Self::rotation_3d_sin_cos(yaw.sin_cos(), pitch.sin_cos(), roll.sin_cos());
+    }
+
+    /// `rotation_3d` with the trigonometric functions of the backend `T`.
+    pub fn rotation_3d_with<T: TrigBackend>(yaw: f32, pitch: f32, roll: f32) -> Self {
+        return Self::rotation_3d_sin_cos(T::sin_cos(yaw), T::sin_cos(pitch), T::sin_cos(roll));
+    }
 
+    fn rotation_3d_sin_cos(
+        (sin_yaw, cos_yaw): (f32, f32),
+        (sin_pitch, cos_pitch): (f32, f32),
+        (sin_roll, cos_roll): (f32, f32),
+    ) -> Self {
         // Directly construct the rotation matrix
         return Matrix::new([
             [
@@ -366,7 +385,29 @@ impl Matrix {
         let result: f32 = self.data.iter().map(|e| e * e).sum();
         return result / (self.len() as f32);
     }
+
+    /// Square root of the sum of all squared elements.
+    pub fn norm_frobenius(&self) -> f32 {
+        let result: f32 = self.data.iter().map(|e| e * e).sum();
+        return sqrtf(result);
+    }
 }
 
+mod banded;
+mod decompositions;
+mod eigen;
+mod iterative;
 mod operators;
+mod orthonormalize;
+mod packed;
+mod rotations;
 mod transforms;
+
+pub use banded::{BandedLu, BandedMatrix, TridiagonalMatrix};
+pub use iterative::{
+    conjugate_gradient, gauss_seidel, jacobi, sor, ConvergenceReport, IterationLimits,
+    LinearOperator, Preconditioner,
+};
+pub use packed::{SymmetricMatrix, Triangle, TriangularMatrix};
+pub use rotations::{Axis, EulerFrame, EulerSequence};
+pub use transforms::GivensRotation;
@@ -1,7 +1,7 @@
 use alloc::vec::Vec;
 
 use super::Matrix;
-use core::ops::{Add, Mul, MulAssign, Neg, Sub};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 impl Mul<f32> for &Matrix {
     type Output = Matrix;
@@ -61,16 +61,11 @@ impl MulAssign<&Matrix> for Matrix {
             rhs.n,
         );
 
-        let mut result_data: Vec<f32> = Vec::with_capacity(self.m * rhs.n);
-        for m in 0..self.m {
-            for o in 0..rhs.n {
-                let value = (0..self.n).map(|n| self[(m, n)] * rhs[(n, o)]).sum();
-                result_data.push(value);
-            }
-        }
-
-        self.data = result_data;
-        core::mem::swap(&mut self.m, &mut self.n);
+        // Every entry of the product reads a whole row of self, so it needs
+        // its own storage
+        let mut result = Matrix::zeros((self.m, rhs.n));
+        self.mul_to(rhs, &mut result);
+        *self = result;
     }
 }
 
@@ -133,3 +128,95 @@ impl Neg for &Matrix {
         return Matrix::from(result_data, self.shape());
     }
 }
+
+impl AddAssign<&Matrix> for Matrix {
+    fn add_assign(&mut self, rhs: &Matrix) {
+        assert!(
+            self.shape() == rhs.shape(),
+            "Can't add {}x{} matrix to {}x{} matrix.",
+            self.m,
+            self.n,
+            rhs.m,
+            rhs.n,
+        );
+
+        for (e, r) in self.data.iter_mut().zip(rhs.data.iter()) {
+            *e += r;
+        }
+    }
+}
+
+impl SubAssign<&Matrix> for Matrix {
+    fn sub_assign(&mut self, rhs: &Matrix) {
+        assert!(
+            self.shape() == rhs.shape(),
+            "Can't subtract {}x{} matrix from {}x{} matrix.",
+            self.m,
+            self.n,
+            rhs.m,
+            rhs.n,
+        );
+
+        for (e, r) in self.data.iter_mut().zip(rhs.data.iter()) {
+            *e -= r;
+        }
+    }
+}
+
+// Non-allocating variants for preallocated workspaces
+
+impl Matrix {
+    /// Copies the elements of a matrix of same shape without allocating.
+    pub fn copy_from(&mut self, source: &Matrix) {
+        assert!(
+            self.shape() == source.shape(),
+            "Can't copy {}x{} matrix into {}x{} matrix.",
+            source.m,
+            source.n,
+            self.m,
+            self.n,
+        );
+
+        self.data.copy_from_slice(&source.data);
+    }
+
+    /// Computes `result = self * rhs` without allocating.
+    pub fn mul_to(&self, rhs: &Matrix, result: &mut Matrix) {
+        assert!(
+            self.n == rhs.m && result.shape() == (self.m, rhs.n),
+            "Can't multiply {}x{} matrix with {}x{} matrix into {}x{} matrix.",
+            self.m,
+            self.n,
+            rhs.m,
+            rhs.n,
+            result.m,
+            result.n,
+        );
+
+        for m in 0..self.m {
+            for o in 0..rhs.n {
+                result.data[m * rhs.n + o] = (0..self.n).map(|n| self[(m, n)] * rhs[(n, o)]).sum();
+            }
+        }
+    }
+
+    /// Computes `result = self * rhs^T` without allocating.
+    pub fn mul_transposed_to(&self, rhs: &Matrix, result: &mut Matrix) {
+        assert!(
+            self.n == rhs.n && result.shape() == (self.m, rhs.m),
+            "Can't multiply {}x{} matrix with transposed {}x{} matrix into {}x{} matrix.",
+            self.m,
+            self.n,
+            rhs.m,
+            rhs.n,
+            result.m,
+            result.n,
+        );
+
+        for m in 0..self.m {
+            for o in 0..rhs.m {
+                result.data[m * rhs.m + o] = (0..self.n).map(|n| self[(m, n)] * rhs[(o, n)]).sum();
+            }
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use libm::sqrtf;
+
+use super::Matrix;
+
+/// Maximum number of Newton steps of the polar decomposition.
+const POLAR_MAX_ITERATIONS: usize = 16;
+const POLAR_TOLERANCE: f32 = 1e-6;
+
+impl Matrix {
+    /// Frobenius norm of the difference of two matrices of same shape.
+    fn distance(&self, rhs: &Matrix) -> f32 {
+        let result: f32 = self
+            .data
+            .iter()
+            .zip(rhs.data.iter())
+            .map(|(a, b)| (a - b) * (a - b))
+            .sum();
+        return sqrtf(result);
+    }
+
+    /// Orthonormalizes the columns inplace with the modified Gram-Schmidt
+    /// method and returns the Frobenius norm of the removed deviation.
+    pub fn gram_schmidt_ip(&mut self) -> f32 {
+        assert!(
+            self.n <= self.m,
+            "Can't orthonormalize {} columns of length {}",
+            self.n,
+            self.m
+        );
+
+        let original = self.clone();
+
+        for k in 0..self.n {
+            let norm = sqrtf((0..self.m).map(|m| self[(m, k)] * self[(m, k)]).sum());
+            assert!(norm > 0.0, "Columns are linearly dependent");
+
+            for m in 0..self.m {
+                self[(m, k)] /= norm;
+            }
+
+            // Remove the new direction from all remaining columns
+            for n in k + 1..self.n {
+                let projection: f32 = (0..self.m).map(|m| self[(m, k)] * self[(m, n)]).sum();
+                for m in 0..self.m {
+                    self[(m, n)] -= projection * self[(m, k)];
+                }
+            }
+        }
+
+        return self.distance(&original);
+    }
+
+    /// Returns the matrix with orthonormalized columns and the Frobenius norm
+    /// of the removed deviation.
+    pub fn gram_schmidt(&self) -> (Matrix, f32) {
+        let mut result = self.clone();
+        let deviation = result.gram_schmidt_ip();
+
+        return (result, deviation);
+    }
+
+    /// Replaces the 3x3 matrix inplace by the nearest rotation matrix (in
+    /// Frobenius norm) and returns the norm of the removed deviation.
+    ///
+    /// The rotation is the orthogonal factor of the polar decomposition,
+    /// computed with the Newton iteration `X = (X + X^-T) / 2`. Returns
+    /// `None` (leaving the matrix unchanged) if the determinant isn't
+    /// positive, i.e. for singular matrices and reflections.
+    pub fn project_so3_ip(&mut self) -> Option<f32> {
+        assert!(self.shape() == (3, 3), "Matrix must be of shape 3x3");
+
+        let det = self.determinant();
+        if det.is_nan() || det <= 0.0 {
+            return None;
+        }
+
+        let original = self.clone();
+        for _ in 0..POLAR_MAX_ITERATIONS {
+            // X^-T = cofactor matrix / det
+            let det = self.determinant();
+            let mut next = self.clone();
+            for m in 0..3 {
+                for n in 0..3 {
+                    next[(m, n)] = (self[(m, n)] + self.cofactor(m, n) / det) / 2.0;
+                }
+            }
+
+            let step = next.distance(self);
+            *self = next;
+            if step < POLAR_TOLERANCE {
+                break;
+            }
+        }
+
+        return Some(self.distance(&original));
+    }
+
+    /// Returns the nearest rotation matrix and the Frobenius norm of the
+    /// removed deviation, or `None` if the determinant isn't positive.
+    pub fn project_so3(&self) -> Option<(Matrix, f32)> {
+        let mut result = self.clone();
+        let deviation = result.project_so3_ip()?;
+
+        return Some((result, deviation));
+    }
+}
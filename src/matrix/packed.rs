@@ -0,0 +1,400 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{self, Mul};
+
+use super::{LinearOperator, Matrix};
+
+/// Offset of `A[i][j]` with `i >= j` in a row-major packed lower triangle.
+fn packed_offset(i: usize, j: usize) -> usize {
+    return i * (i + 1) / 2 + j;
+}
+
+fn packed_len(n: usize) -> usize {
+    return n * (n + 1) / 2;
+}
+
+/// Symmetric matrix that only stores its lower triangle, i.e. n(n+1)/2
+/// elements.
+#[derive(Debug, Clone)]
+pub struct SymmetricMatrix {
+    pub n: usize,
+    data: Vec<f32>,
+}
+
+impl ops::Index<(usize, usize)> for SymmetricMatrix {
+    type Output = f32;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        assert!(
+            (index.0 < self.n) && (index.1 < self.n),
+            "Matrix index is out-of-bounds."
+        );
+        let (i, j) = if index.0 >= index.1 {
+            index
+        } else {
+            (index.1, index.0)
+        };
+        return &self.data[packed_offset(i, j)];
+    }
+}
+
+impl ops::IndexMut<(usize, usize)> for SymmetricMatrix {
+    /// Writing `A[i][j]` also writes `A[j][i]`.
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        assert!(
+            (index.0 < self.n) && (index.1 < self.n),
+            "Matrix index is out-of-bounds."
+        );
+        let (i, j) = if index.0 >= index.1 {
+            index
+        } else {
+            (index.1, index.0)
+        };
+        return &mut self.data[packed_offset(i, j)];
+    }
+}
+
+impl SymmetricMatrix {
+    pub fn zeros(n: usize) -> Self {
+        return Self {
+            n,
+            data: vec![0.0; packed_len(n)],
+        };
+    }
+
+    pub fn identity(n: usize) -> Self {
+        let mut result = Self::zeros(n);
+        for i in 0..n {
+            result[(i, i)] = 1.0;
+        }
+        return result;
+    }
+
+    /// Packs the lower triangle of a quadratic matrix. The upper triangle is
+    /// ignored.
+    pub fn from_dense(matrix: &Matrix) -> Self {
+        assert!(matrix.is_quadratic(), "Matrix must be quadratic");
+
+        let mut data = Vec::with_capacity(packed_len(matrix.m));
+        for i in 0..matrix.m {
+            for j in 0..=i {
+                data.push(matrix[(i, j)]);
+            }
+        }
+
+        return Self { n: matrix.m, data };
+    }
+
+    pub fn to_dense(&self) -> Matrix {
+        let mut result = Matrix::zeros((self.n, self.n));
+
+        for i in 0..self.n {
+            for j in 0..=i {
+                result[(i, j)] = self[(i, j)];
+                result[(j, i)] = self[(i, j)];
+            }
+        }
+
+        return result;
+    }
+
+    pub fn len(&self) -> usize {
+        return self.data.len();
+    }
+
+    /// Symmetric rank-k update `self = alpha * A * A^T + beta * self` for a
+    /// n x k matrix `A`. Only one triangle is computed, so the result stays
+    /// exactly symmetric.
+    pub fn rank_k_update(&mut self, alpha: f32, a: &Matrix, beta: f32) {
+        assert!(
+            a.m == self.n,
+            "Can't update {}x{} symmetric matrix with {}x{} matrix.",
+            self.n,
+            self.n,
+            a.m,
+            a.n,
+        );
+
+        for i in 0..self.n {
+            let row_i = &a.data[i * a.n..(i + 1) * a.n];
+            for j in 0..=i {
+                let row_j = &a.data[j * a.n..(j + 1) * a.n];
+                let value: f32 = row_i.iter().zip(row_j.iter()).map(|(x, y)| x * y).sum();
+
+                let e = &mut self.data[packed_offset(i, j)];
+                *e = alpha * value + beta * *e;
+            }
+        }
+    }
+}
+
+impl LinearOperator for SymmetricMatrix {
+    fn size(&self) -> usize {
+        return self.n;
+    }
+
+    fn diagonal(&self, i: usize) -> f32 {
+        return self[(i, i)];
+    }
+
+    fn row_dot(&self, i: usize, x: &[f32]) -> f32 {
+        return (0..self.n).map(|j| self[(i, j)] * x[j]).sum();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Triangle {
+    Lower,
+    Upper,
+}
+
+/// Triangular matrix that only stores its non-zero triangle, i.e. n(n+1)/2
+/// elements.
+///
+/// An upper triangular matrix shares the layout of its lower transpose, so
+/// transposing never moves any data.
+#[derive(Debug, Clone)]
+pub struct TriangularMatrix {
+    pub n: usize,
+    pub triangle: Triangle,
+    data: Vec<f32>,
+}
+
+impl ops::Index<(usize, usize)> for TriangularMatrix {
+    type Output = f32;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        assert!(
+            (index.0 < self.n) && (index.1 < self.n),
+            "Matrix index is out-of-bounds."
+        );
+        return match self.offset(index.0, index.1) {
+            Some(offset) => &self.data[offset],
+            None => &0.0,
+        };
+    }
+}
+
+impl ops::IndexMut<(usize, usize)> for TriangularMatrix {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        assert!(
+            (index.0 < self.n) && (index.1 < self.n),
+            "Matrix index is out-of-bounds."
+        );
+        let offset = self
+            .offset(index.0, index.1)
+            .expect("Matrix index is outside of the triangle.");
+        return &mut self.data[offset];
+    }
+}
+
+impl TriangularMatrix {
+    pub fn zeros(n: usize, triangle: Triangle) -> Self {
+        return Self {
+            n,
+            triangle,
+            data: vec![0.0; packed_len(n)],
+        };
+    }
+
+    /// Packs one triangle of a quadratic matrix. The other one is ignored.
+    pub fn from_dense(matrix: &Matrix, triangle: Triangle) -> Self {
+        assert!(matrix.is_quadratic(), "Matrix must be quadratic");
+
+        let mut result = Self::zeros(matrix.m, triangle);
+        for i in 0..result.n {
+            for j in 0..=i {
+                match triangle {
+                    Triangle::Lower => result[(i, j)] = matrix[(i, j)],
+                    Triangle::Upper => result[(j, i)] = matrix[(j, i)],
+                }
+            }
+        }
+
+        return result;
+    }
+
+    pub fn to_dense(&self) -> Matrix {
+        let mut result = Matrix::zeros((self.n, self.n));
+
+        for i in 0..self.n {
+            for j in 0..=i {
+                match self.triangle {
+                    Triangle::Lower => result[(i, j)] = self[(i, j)],
+                    Triangle::Upper => result[(j, i)] = self[(j, i)],
+                }
+            }
+        }
+
+        return result;
+    }
+
+    pub fn len(&self) -> usize {
+        return self.data.len();
+    }
+
+    fn offset(&self, i: usize, j: usize) -> Option<usize> {
+        return match self.triangle {
+            Triangle::Lower if i >= j => Some(packed_offset(i, j)),
+            Triangle::Upper if i <= j => Some(packed_offset(j, i)),
+            _ => None,
+        };
+    }
+
+    #[allow(non_snake_case)]
+    /// Transposes the matrix inplace
+    pub fn T_ip(&mut self) {
+        self.triangle = match self.triangle {
+            Triangle::Lower => Triangle::Upper,
+            Triangle::Upper => Triangle::Lower,
+        };
+    }
+
+    #[allow(non_snake_case)]
+    /// Returns the transpose of the matrix
+    pub fn T(&self) -> TriangularMatrix {
+        let mut result = self.clone();
+        result.T_ip();
+
+        return result;
+    }
+
+    /// Solves `A * x = rhs` by forward or back substitution.
+    pub fn solve(&self, rhs: &Matrix) -> Matrix {
+        assert!(
+            rhs.m == self.n,
+            "Can't solve {}x{} triangular system for {}x{} matrix.",
+            self.n,
+            self.n,
+            rhs.m,
+            rhs.n,
+        );
+
+        let mut x = rhs.clone();
+        for column in 0..rhs.n {
+            for step in 0..self.n {
+                let i = match self.triangle {
+                    Triangle::Lower => step,
+                    Triangle::Upper => self.n - 1 - step,
+                };
+                let known: f32 = match self.triangle {
+                    Triangle::Lower => (0..i).map(|j| self[(i, j)] * x[(j, column)]).sum(),
+                    Triangle::Upper => (i + 1..self.n).map(|j| self[(i, j)] * x[(j, column)]).sum(),
+                };
+                x[(i, column)] = (x[(i, column)] - known) / self[(i, i)];
+            }
+        }
+
+        return x;
+    }
+}
+
+impl Mul<&Matrix> for &SymmetricMatrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: &Matrix) -> Self::Output {
+        assert!(
+            self.n == rhs.m,
+            "Can't multiply {}x{} matrix with {}x{} matrix.",
+            self.n,
+            self.n,
+            rhs.m,
+            rhs.n,
+        );
+
+        let mut result_data: Vec<f32> = Vec::with_capacity(self.n * rhs.n);
+        for m in 0..self.n {
+            for o in 0..rhs.n {
+                let value = (0..self.n).map(|n| self[(m, n)] * rhs[(n, o)]).sum();
+                result_data.push(value);
+            }
+        }
+
+        return Matrix::from(result_data, (self.n, rhs.n));
+    }
+}
+
+impl Mul<&SymmetricMatrix> for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: &SymmetricMatrix) -> Self::Output {
+        assert!(
+            self.n == rhs.n,
+            "Can't multiply {}x{} matrix with {}x{} matrix.",
+            self.m,
+            self.n,
+            rhs.n,
+            rhs.n,
+        );
+
+        let mut result_data: Vec<f32> = Vec::with_capacity(self.m * rhs.n);
+        for m in 0..self.m {
+            for o in 0..rhs.n {
+                let value = (0..self.n).map(|n| self[(m, n)] * rhs[(n, o)]).sum();
+                result_data.push(value);
+            }
+        }
+
+        return Matrix::from(result_data, (self.m, rhs.n));
+    }
+}
+
+impl Mul<&Matrix> for &TriangularMatrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: &Matrix) -> Self::Output {
+        assert!(
+            self.n == rhs.m,
+            "Can't multiply {}x{} matrix with {}x{} matrix.",
+            self.n,
+            self.n,
+            rhs.m,
+            rhs.n,
+        );
+
+        let mut result_data: Vec<f32> = Vec::with_capacity(self.n * rhs.n);
+        for m in 0..self.n {
+            // Skip the zero triangle
+            let columns = match self.triangle {
+                Triangle::Lower => 0..m + 1,
+                Triangle::Upper => m..self.n,
+            };
+            for o in 0..rhs.n {
+                let value = columns.clone().map(|n| self[(m, n)] * rhs[(n, o)]).sum();
+                result_data.push(value);
+            }
+        }
+
+        return Matrix::from(result_data, (self.n, rhs.n));
+    }
+}
+
+impl Mul<&TriangularMatrix> for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: &TriangularMatrix) -> Self::Output {
+        assert!(
+            self.n == rhs.n,
+            "Can't multiply {}x{} matrix with {}x{} matrix.",
+            self.m,
+            self.n,
+            rhs.n,
+            rhs.n,
+        );
+
+        let mut result_data: Vec<f32> = Vec::with_capacity(self.m * rhs.n);
+        for m in 0..self.m {
+            for o in 0..rhs.n {
+                // Skip the zero triangle
+                let rows = match rhs.triangle {
+                    Triangle::Lower => o..rhs.n,
+                    Triangle::Upper => 0..o + 1,
+                };
+                let value = rows.map(|n| self[(m, n)] * rhs[(n, o)]).sum();
+                result_data.push(value);
+            }
+        }
+
+        return Matrix::from(result_data, (self.m, rhs.n));
+    }
+}
@@ -0,0 +1,279 @@
+use libm::{acosf, asinf, atan2f, sqrtf};
+
+use super::Matrix;
+use crate::angle::Angle;
+use crate::trig::TrigBackend;
+
+/// Singularity threshold for `|sin|` / `|cos|` of the middle Euler angle.
+const GIMBAL_LOCK_EPSILON: f32 = 1e-6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl Axis {
+    fn index(self) -> usize {
+        return match self {
+            Axis::X => 0,
+            Axis::Y => 1,
+            Axis::Z => 2,
+        };
+    }
+}
+
+/// Order of the three elementary rotations of an Euler angle triple.
+///
+/// Tait-Bryan sequences rotate about three different axes, proper Euler
+/// sequences repeat the first axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+// The sequences are conventionally spelled as the axes in upper case
+#[allow(clippy::upper_case_acronyms)]
+pub enum EulerSequence {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+impl EulerSequence {
+    pub fn axes(self) -> [Axis; 3] {
+        use Axis::{X, Y, Z};
+
+        return match self {
+            EulerSequence::XYZ => [X, Y, Z],
+            EulerSequence::XZY => [X, Z, Y],
+            EulerSequence::YXZ => [Y, X, Z],
+            EulerSequence::YZX => [Y, Z, X],
+            EulerSequence::ZXY => [Z, X, Y],
+            EulerSequence::ZYX => [Z, Y, X],
+            EulerSequence::XYX => [X, Y, X],
+            EulerSequence::XZX => [X, Z, X],
+            EulerSequence::YXY => [Y, X, Y],
+            EulerSequence::YZY => [Y, Z, Y],
+            EulerSequence::ZXZ => [Z, X, Z],
+            EulerSequence::ZYZ => [Z, Y, Z],
+        };
+    }
+
+    pub fn reversed(self) -> EulerSequence {
+        return match self {
+            EulerSequence::XYZ => EulerSequence::ZYX,
+            EulerSequence::XZY => EulerSequence::YZX,
+            EulerSequence::YXZ => EulerSequence::ZXY,
+            EulerSequence::YZX => EulerSequence::XZY,
+            EulerSequence::ZXY => EulerSequence::YXZ,
+            EulerSequence::ZYX => EulerSequence::XYZ,
+            proper => proper,
+        };
+    }
+}
+
+/// Intrinsic rotations are applied about the axes of the rotating body,
+/// extrinsic rotations about the fixed world axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EulerFrame {
+    Intrinsic,
+    Extrinsic,
+}
+
+impl Matrix {
+    /// Elementary rotation about a single coordinate axis.
+    pub fn rotation_axis<A: Angle>(axis: Axis, angle: A) -> Self {
+        return Self::rotation_axis_sin_cos(axis, angle.sin_cos());
+    }
+
+    /// `rotation_axis` with the trigonometric functions of the backend `T`.
+    pub fn rotation_axis_with<T: TrigBackend>(axis: Axis, angle: f32) -> Self {
+        return Self::rotation_axis_sin_cos(axis, T::sin_cos(angle));
+    }
+
+    fn rotation_axis_sin_cos(axis: Axis, (sin, cos): (f32, f32)) -> Self {
+        return match axis {
+            Axis::X => Matrix::new([[1.0, 0.0, 0.0], [0.0, cos, -sin], [0.0, sin, cos]]),
+            Axis::Y => Matrix::new([[cos, 0.0, sin], [0.0, 1.0, 0.0], [-sin, 0.0, cos]]),
+            Axis::Z => Matrix::new([[cos, -sin, 0.0], [sin, cos, 0.0], [0.0, 0.0, 1.0]]),
+        };
+    }
+
+    /// Rotation matrix of the Euler angles `(a, b, c)`, applied about the axes
+    /// of `sequence` in order.
+    ///
+    /// `rotation_3d(yaw, pitch, roll)` equals the intrinsic ZYX sequence.
+    pub fn rotation_euler<A: Angle>(
+        sequence: EulerSequence,
+        frame: EulerFrame,
+        a: A,
+        b: A,
+        c: A,
+    ) -> Self {
+        let [first, second, third] = sequence.axes();
+        return Self::compose_euler(
+            frame,
+            Matrix::rotation_axis(first, a),
+            Matrix::rotation_axis(second, b),
+            Matrix::rotation_axis(third, c),
+        );
+    }
+
+    /// `rotation_euler` with the trigonometric functions of the backend `T`.
+    pub fn rotation_euler_with<T: TrigBackend>(
+        sequence: EulerSequence,
+        frame: EulerFrame,
+        a: f32,
+        b: f32,
+        c: f32,
+    ) -> Self {
+        let [first, second, third] = sequence.axes();
+        return Self::compose_euler(
+            frame,
+            Matrix::rotation_axis_with::<T>(first, a),
+            Matrix::rotation_axis_with::<T>(second, b),
+            Matrix::rotation_axis_with::<T>(third, c),
+        );
+    }
+
+    /// Combines the elementary rotations of the first, second and third
+    /// axis of a sequence.
+    fn compose_euler(frame: EulerFrame, r1: Matrix, r2: Matrix, r3: Matrix) -> Self {
+        return match frame {
+            EulerFrame::Intrinsic => &(&r1 * &r2) * &r3,
+            EulerFrame::Extrinsic => &(&r3 * &r2) * &r1,
+        };
+    }
+
+    /// Rotation by `angle` about `axis` (a column vector of length 3) using
+    /// Rodrigues' formula `R = I + sin * K + (1 - cos) * K^2`.
+    pub fn rotation_axis_angle<A: Angle>(axis: &Matrix, angle: A) -> Self {
+        return Self::rotation_axis_angle_sin_cos(axis, angle.sin_cos());
+    }
+
+    /// `rotation_axis_angle` with the trigonometric functions of the backend
+    /// `T`.
+    pub fn rotation_axis_angle_with<T: TrigBackend>(axis: &Matrix, angle: f32) -> Self {
+        return Self::rotation_axis_angle_sin_cos(axis, T::sin_cos(angle));
+    }
+
+    fn rotation_axis_angle_sin_cos(axis: &Matrix, (sin, cos): (f32, f32)) -> Self {
+        assert!(
+            axis.is_column_vector() && axis.len() == 3,
+            "Provided axis isn't a column vector of length 3"
+        );
+
+        let norm = sqrtf(axis.T().dot(axis));
+        if norm == 0.0 {
+            return Matrix::identity(3);
+        }
+
+        let (x, y, z) = (
+            axis[(0, 0)] / norm,
+            axis[(1, 0)] / norm,
+            axis[(2, 0)] / norm,
+        );
+        let t = 1.0 - cos;
+
+        return Matrix::new([
+            [t * x * x + cos, t * x * y - sin * z, t * x * z + sin * y],
+            [t * x * y + sin * z, t * y * y + cos, t * y * z - sin * x],
+            [t * x * z - sin * y, t * y * z + sin * x, t * z * z + cos],
+        ]);
+    }
+
+    /// Extracts the Euler angles `(a, b, c)` so that
+    /// `rotation_euler(sequence, frame, a, b, c)` reproduces the rotation.
+    ///
+    /// At gimbal lock only the sum or difference of the outer angles is
+    /// defined. The last applied angle is then reported as 0.
+    pub fn to_euler(&self, sequence: EulerSequence, frame: EulerFrame) -> (f32, f32, f32) {
+        assert!(
+            self.shape() == (3, 3),
+            "Rotation matrix must be of shape 3x3"
+        );
+
+        // An extrinsic sequence equals the reversed intrinsic one
+        if frame == EulerFrame::Extrinsic {
+            let (c, b, a) = self.to_euler(sequence.reversed(), EulerFrame::Intrinsic);
+            return (a, b, c);
+        }
+
+        let [first, second, third] = sequence.axes();
+        let i = first.index();
+        let j = second.index();
+        let l = 3 - i - j;
+        // +1 for cyclic axis permutations (XYZ, YZX, ZXY), -1 otherwise
+        let parity = if (i + 1) % 3 == j { 1.0 } else { -1.0 };
+        let r = |row: usize, column: usize| self[(row, column)];
+
+        let (a, b, c);
+        if third != first {
+            // Tait-Bryan sequence
+            let sin_b = (parity * r(i, l)).clamp(-1.0, 1.0);
+            b = asinf(sin_b);
+
+            if 1.0 - sin_b.abs() > GIMBAL_LOCK_EPSILON {
+                a = atan2f(-parity * r(j, l), r(l, l));
+                c = atan2f(-parity * r(i, j), r(i, i));
+            } else {
+                a = atan2f(parity * r(l, j), r(j, j));
+                c = 0.0;
+            }
+        } else {
+            // Proper Euler sequence
+            let cos_b = r(i, i).clamp(-1.0, 1.0);
+            b = acosf(cos_b);
+
+            if 1.0 - cos_b.abs() > GIMBAL_LOCK_EPSILON {
+                a = atan2f(r(j, i), -parity * r(l, i));
+                c = atan2f(r(i, j), parity * r(i, l));
+            } else {
+                a = atan2f(parity * r(l, j), r(j, j));
+                c = 0.0;
+            }
+        }
+
+        return (a, b, c);
+    }
+
+    /// Returns the unit rotation axis as column vector and the rotation
+    /// angle in `[0, π]`. Without rotation the axis defaults to X.
+    pub fn to_axis_angle(&self) -> (Matrix, f32) {
+        assert!(
+            self.shape() == (3, 3),
+            "Rotation matrix must be of shape 3x3"
+        );
+
+        let r = |row: usize, column: usize| self[(row, column)];
+        let trace = r(0, 0) + r(1, 1) + r(2, 2);
+        let angle = acosf(((trace - 1.0) / 2.0).clamp(-1.0, 1.0));
+
+        let mut axis = Matrix::new([
+            [r(2, 1) - r(1, 2)],
+            [r(0, 2) - r(2, 0)],
+            [r(1, 0) - r(0, 1)],
+        ]);
+        let mut norm = sqrtf(axis.T().dot(&axis));
+
+        if norm < 1e-4 {
+            if angle < 1.0 {
+                return (Matrix::new([[1.0], [0.0], [0.0]]), 0.0);
+            }
+
+            // Close to π the skew part vanishes, so use R + I = 2 * axis * axis^T
+            let k = (0..3).max_by(|&p, &q| r(p, p).total_cmp(&r(q, q))).unwrap();
+            axis = (self + &Matrix::identity(3)).get_column(k);
+            norm = sqrtf(axis.T().dot(&axis));
+        }
+
+        return (&axis * (1.0 / norm), angle);
+    }
+}
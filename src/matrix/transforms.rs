@@ -1,4 +1,42 @@
+use alloc::vec;
+use libm::{sqrtf, tanf};
+
 use super::Matrix;
+use crate::angle::Angle;
+
+/// Plane rotation `G = [c s; -s c]` that zeroes the second component of a
+/// 2-vector.
+#[derive(Debug, Clone, Copy)]
+pub struct GivensRotation {
+    pub c: f32,
+    pub s: f32,
+}
+
+impl GivensRotation {
+    /// Computes the rotation with `G * [a; b] = [r; 0]` and returns it
+    /// together with `r`, avoiding overflow for large inputs.
+    pub fn new(a: f32, b: f32) -> (Self, f32) {
+        if b == 0.0 {
+            return (Self { c: 1.0, s: 0.0 }, a);
+        }
+
+        if b.abs() > a.abs() {
+            let t = a / b;
+            let u = sqrtf(1.0 + t * t).copysign(b);
+            let s = 1.0 / u;
+            return (Self { c: s * t, s }, b * u);
+        } else {
+            let t = b / a;
+            let u = sqrtf(1.0 + t * t).copysign(a);
+            let c = 1.0 / u;
+            return (Self { c, s: c * t }, a * u);
+        }
+    }
+
+    fn rotate(&self, x: f32, y: f32) -> (f32, f32) {
+        return (self.c * x + self.s * y, self.c * y - self.s * x);
+    }
+}
 
 impl Matrix {
     pub fn transform_householder(&self) -> Matrix {
@@ -12,6 +50,113 @@ impl Matrix {
         return &Matrix::identity(self.len()) - &rhs;
     }
 
+    /// Applies the Householder reflector `H = I - 2 * v * v^T / (v^T * v)`
+    /// from the left, i.e. `self = H * self`, without forming `H`.
+    ///
+    /// The reflector acts on the rows `offset..offset + v.len()`.
+    pub fn apply_householder_left(&mut self, v: &Matrix, offset: usize) {
+        assert!(
+            v.is_column_vector(),
+            "Provided matrix isn't a column vector"
+        );
+        assert!(
+            offset + v.len() <= self.m,
+            "Reflector of length {} at row {} exceeds {}x{} matrix.",
+            v.len(),
+            offset,
+            self.m,
+            self.n,
+        );
+
+        let v_norm = v.T().dot(v);
+        if v_norm == 0.0 {
+            return;
+        }
+
+        // self -= v * (2 / v^T v) * (v^T * self)
+        for n in 0..self.n {
+            let projection: f32 = (0..v.len())
+                .map(|k| v.data[k] * self[(offset + k, n)])
+                .sum();
+            let scale = 2.0 * projection / v_norm;
+            for k in 0..v.len() {
+                self[(offset + k, n)] -= scale * v.data[k];
+            }
+        }
+    }
+
+    /// Applies the Householder reflector `H = I - 2 * v * v^T / (v^T * v)`
+    /// from the right, i.e. `self = self * H`, without forming `H`.
+    ///
+    /// The reflector acts on the columns `offset..offset + v.len()`.
+    pub fn apply_householder_right(&mut self, v: &Matrix, offset: usize) {
+        assert!(
+            v.is_column_vector(),
+            "Provided matrix isn't a column vector"
+        );
+        assert!(
+            offset + v.len() <= self.n,
+            "Reflector of length {} at column {} exceeds {}x{} matrix.",
+            v.len(),
+            offset,
+            self.m,
+            self.n,
+        );
+
+        let v_norm = v.T().dot(v);
+        if v_norm == 0.0 {
+            return;
+        }
+
+        // self -= (self * v) * (2 / v^T v) * v^T
+        for m in 0..self.m {
+            let row = &mut self.data[m * self.n + offset..m * self.n + offset + v.len()];
+            let projection: f32 = row.iter().zip(v.data.iter()).map(|(a, v)| a * v).sum();
+            let scale = 2.0 * projection / v_norm;
+            for (a, v) in row.iter_mut().zip(v.data.iter()) {
+                *a -= scale * v;
+            }
+        }
+    }
+
+    /// Applies the Givens rotation to the rows `i` and `k` inplace, i.e.
+    /// `self = G * self` with `G` embedded into the identity.
+    pub fn apply_givens_rows(&mut self, rotation: &GivensRotation, i: usize, k: usize) {
+        assert!(
+            i < self.m && k < self.m && i != k,
+            "Invalid row pair ({}, {}) for {}x{} matrix.",
+            i,
+            k,
+            self.m,
+            self.n,
+        );
+
+        for n in 0..self.n {
+            let (x, y) = rotation.rotate(self[(i, n)], self[(k, n)]);
+            self[(i, n)] = x;
+            self[(k, n)] = y;
+        }
+    }
+
+    /// Applies the transposed Givens rotation to the columns `i` and `k`
+    /// inplace, i.e. `self = self * G^T` with `G` embedded into the identity.
+    pub fn apply_givens_columns(&mut self, rotation: &GivensRotation, i: usize, k: usize) {
+        assert!(
+            i < self.n && k < self.n && i != k,
+            "Invalid column pair ({}, {}) for {}x{} matrix.",
+            i,
+            k,
+            self.m,
+            self.n,
+        );
+
+        for m in 0..self.m {
+            let (x, y) = rotation.rotate(self[(m, i)], self[(m, k)]);
+            self[(m, i)] = x;
+            self[(m, k)] = y;
+        }
+    }
+
     // pub fn decompose_QR(&self) -> (Matrix, Matrix) {
     //     let mut Q = Matrix::identity(self.m);
     //     let R: Matrix;
@@ -21,4 +166,197 @@ impl Matrix {
 
     //     return (Q, R);
     // }
+
+    // Homogeneous transforms
+
+    /// Embeds a quadratic linear map into a homogeneous transform of one
+    /// dimension higher.
+    pub fn to_homogeneous(&self) -> Matrix {
+        assert!(self.is_quadratic(), "Linear map must be quadratic");
+
+        let mut result = Matrix::identity(self.m + 1);
+        for m in 0..self.m {
+            for n in 0..self.n {
+                result[(m, n)] = self[(m, n)];
+            }
+        }
+
+        return result;
+    }
+
+    pub fn translation_2d(tx: f32, ty: f32) -> Self {
+        return Matrix::new([[1.0, 0.0, tx], [0.0, 1.0, ty], [0.0, 0.0, 1.0]]);
+    }
+
+    pub fn translation_3d(tx: f32, ty: f32, tz: f32) -> Self {
+        return Matrix::new([
+            [1.0, 0.0, 0.0, tx],
+            [0.0, 1.0, 0.0, ty],
+            [0.0, 0.0, 1.0, tz],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+    }
+
+    pub fn scaling_2d(sx: f32, sy: f32) -> Self {
+        return Matrix::new([[sx, 0.0, 0.0], [0.0, sy, 0.0], [0.0, 0.0, 1.0]]);
+    }
+
+    pub fn scaling_3d(sx: f32, sy: f32, sz: f32) -> Self {
+        return Matrix::new([
+            [sx, 0.0, 0.0, 0.0],
+            [0.0, sy, 0.0, 0.0],
+            [0.0, 0.0, sz, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+    }
+
+    /// Shear where `xy` is the shift of x per unit of y and so on.
+    pub fn shear_2d(xy: f32, yx: f32) -> Self {
+        return Matrix::new([[1.0, xy, 0.0], [yx, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+    }
+
+    /// Shear where `xy` is the shift of x per unit of y and so on.
+    pub fn shear_3d(xy: f32, xz: f32, yx: f32, yz: f32, zx: f32, zy: f32) -> Self {
+        return Matrix::new([
+            [1.0, xy, xz, 0.0],
+            [yx, 1.0, yz, 0.0],
+            [zx, zy, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+    }
+
+    /// Rotation by `angle` about the line through `point` along `axis`, both
+    /// column vectors of length 3.
+    pub fn rotation_about_line<A: Angle>(axis: &Matrix, angle: A, point: &Matrix) -> Self {
+        assert!(
+            point.is_column_vector() && point.len() == 3,
+            "Provided point isn't a column vector of length 3"
+        );
+
+        let rotation = Matrix::rotation_axis_angle(axis, angle);
+
+        // p - R * p moves the rotation center back onto itself
+        let offset = point - &(&rotation * point);
+        let mut result = rotation.to_homogeneous();
+        for m in 0..3 {
+            result[(m, 3)] = offset[(m, 0)];
+        }
+
+        return result;
+    }
+
+    /// Right-handed view transform of a camera at `eye` looking at `target`,
+    /// all column vectors of length 3. The camera looks along its negative
+    /// z-axis.
+    pub fn look_at(eye: &Matrix, target: &Matrix, up: &Matrix) -> Self {
+        let normalize = |v: Matrix| {
+            let norm = sqrtf(v.T().dot(&v));
+            assert!(norm > 0.0, "Degenerate look-at configuration");
+            return &v * (1.0 / norm);
+        };
+
+        let forward = normalize(target - eye);
+        let right = normalize(forward.cross(up));
+        let up = right.cross(&forward);
+
+        let mut result = Matrix::identity(4);
+        for n in 0..3 {
+            result[(0, n)] = right[(n, 0)];
+            result[(1, n)] = up[(n, 0)];
+            result[(2, n)] = -forward[(n, 0)];
+        }
+        result[(0, 3)] = -right.T().dot(eye);
+        result[(1, 3)] = -up.T().dot(eye);
+        result[(2, 3)] = forward.T().dot(eye);
+
+        return result;
+    }
+
+    /// Orthographic projection of the given view volume onto the `[-1, 1]`
+    /// cube, with the OpenGL clip space conventions.
+    pub fn orthographic(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Self {
+        return Matrix::new([
+            [
+                2.0 / (right - left),
+                0.0,
+                0.0,
+                -(right + left) / (right - left),
+            ],
+            [
+                0.0,
+                2.0 / (top - bottom),
+                0.0,
+                -(top + bottom) / (top - bottom),
+            ],
+            [0.0, 0.0, -2.0 / (far - near), -(far + near) / (far - near)],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+    }
+
+    /// Perspective projection with the vertical field of view `fov_y` (rad),
+    /// with the OpenGL clip space conventions.
+    pub fn perspective(fov_y: f32, aspect: f32, near: f32, far: f32) -> Self {
+        let f = 1.0 / tanf(fov_y / 2.0);
+
+        return Matrix::new([
+            [f / aspect, 0.0, 0.0, 0.0],
+            [0.0, f, 0.0, 0.0],
+            [
+                0.0,
+                0.0,
+                (far + near) / (near - far),
+                2.0 * far * near / (near - far),
+            ],
+            [0.0, 0.0, -1.0, 0.0],
+        ]);
+    }
+
+    /// Inverts a homogeneous rigid transform `[R t; 0 1]` as `[R^T -R^T*t; 0 1]`
+    /// without a general matrix inversion.
+    pub fn rigid_inverse(&self) -> Matrix {
+        assert!(
+            self.is_quadratic() && self.m >= 2,
+            "Provided matrix isn't a homogeneous transform"
+        );
+
+        let d = self.m - 1;
+        let mut result = Matrix::identity(self.m);
+        for m in 0..d {
+            for n in 0..d {
+                result[(m, n)] = self[(n, m)];
+            }
+            result[(m, d)] = -(0..d).map(|n| self[(n, m)] * self[(n, d)]).sum::<f32>();
+        }
+
+        return result;
+    }
+
+    /// Applies the homogeneous transform to a set of points stored as the
+    /// columns of `points`, including the perspective division.
+    pub fn transform_points(&self, points: &Matrix) -> Matrix {
+        assert!(
+            self.is_quadratic() && self.m == points.m + 1,
+            "Can't apply {}x{} transform to {}-dimensional points.",
+            self.m,
+            self.n,
+            points.m,
+        );
+
+        let d = points.m;
+        let mut result_data = vec![0.0; points.len()];
+
+        for o in 0..points.n {
+            let homogeneous = |m: usize| {
+                let linear: f32 = (0..d).map(|n| self[(m, n)] * points[(n, o)]).sum();
+                return linear + self[(m, d)];
+            };
+
+            let w = homogeneous(d);
+            for m in 0..d {
+                result_data[m * points.n + o] = homogeneous(m) / w;
+            }
+        }
+
+        return Matrix::from(result_data, points.shape());
+    }
 }
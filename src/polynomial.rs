@@ -0,0 +1,279 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::{fmt, ops};
+
+use crate::matrix::Matrix;
+
+/// Polynomial `c[0] + c[1] * x + ... + c[n] * x^n` with real coefficients,
+/// stored in ascending powers without trailing zeros.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial {
+    coefficients: Vec<f32>,
+}
+
+impl fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        for (power, &c) in self.coefficients.iter().enumerate().rev() {
+            let c = if power == self.degree() {
+                c
+            } else if c < 0.0 {
+                write!(f, " - ")?;
+                -c
+            } else {
+                write!(f, " + ")?;
+                c
+            };
+            match power {
+                0 => write!(f, "{:.3}", c)?,
+                1 => write!(f, "{:.3}x", c)?,
+                _ => write!(f, "{:.3}x^{}", c, power)?,
+            }
+        }
+        return Ok(());
+    }
+}
+
+impl ops::Add<&Polynomial> for &Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, rhs: &Polynomial) -> Self::Output {
+        let len = usize::max(self.coefficients.len(), rhs.coefficients.len());
+        let coefficients = (0..len)
+            .map(|i| self.coefficient(i) + rhs.coefficient(i))
+            .collect();
+
+        return Polynomial::from(coefficients);
+    }
+}
+
+impl ops::Sub<&Polynomial> for &Polynomial {
+    type Output = Polynomial;
+
+    fn sub(self, rhs: &Polynomial) -> Self::Output {
+        let len = usize::max(self.coefficients.len(), rhs.coefficients.len());
+        let coefficients = (0..len)
+            .map(|i| self.coefficient(i) - rhs.coefficient(i))
+            .collect();
+
+        return Polynomial::from(coefficients);
+    }
+}
+
+impl ops::Neg for &Polynomial {
+    type Output = Polynomial;
+
+    fn neg(self) -> Self::Output {
+        return Polynomial::from(self.coefficients.iter().map(|c| -c).collect());
+    }
+}
+
+impl ops::Mul<f32> for &Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        return Polynomial::from(self.coefficients.iter().map(|c| c * rhs).collect());
+    }
+}
+
+impl ops::Mul<&Polynomial> for &Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, rhs: &Polynomial) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return Polynomial::zero();
+        }
+
+        let mut coefficients = vec![0.0; self.coefficients.len() + rhs.coefficients.len() - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in rhs.coefficients.iter().enumerate() {
+                coefficients[i + j] += a * b;
+            }
+        }
+
+        return Polynomial::from(coefficients);
+    }
+}
+
+impl ops::Div<&Polynomial> for &Polynomial {
+    type Output = Polynomial;
+
+    /// Quotient of the polynomial division, see `div_rem`.
+    fn div(self, rhs: &Polynomial) -> Self::Output {
+        return self.div_rem(rhs).0;
+    }
+}
+
+impl ops::Rem<&Polynomial> for &Polynomial {
+    type Output = Polynomial;
+
+    /// Remainder of the polynomial division, see `div_rem`.
+    fn rem(self, rhs: &Polynomial) -> Self::Output {
+        return self.div_rem(rhs).1;
+    }
+}
+
+impl Polynomial {
+    // Constructors
+
+    /// Creates the polynomial from coefficients in ascending powers.
+    pub fn new(coefficients: &[f32]) -> Self {
+        return Self::from(coefficients.to_vec());
+    }
+
+    /// Creates the polynomial from coefficients in ascending powers.
+    pub fn from(mut coefficients: Vec<f32>) -> Self {
+        while coefficients.last() == Some(&0.0) {
+            coefficients.pop();
+        }
+        return Self { coefficients };
+    }
+
+    pub fn zero() -> Self {
+        return Self {
+            coefficients: Vec::new(),
+        };
+    }
+
+    /// Creates the monic polynomial `(x - r[0]) * ... * (x - r[n-1])`.
+    pub fn from_roots(roots: &[f32]) -> Self {
+        let mut result = Self::new(&[1.0]);
+        for root in roots {
+            result = &result * &Self::new(&[-root, 1.0]);
+        }
+        return result;
+    }
+
+    // Properties
+
+    /// Coefficients in ascending powers, empty for the zero polynomial.
+    pub fn coefficients(&self) -> &[f32] {
+        return &self.coefficients;
+    }
+
+    /// Coefficient of `x^power`, 0 above the degree.
+    pub fn coefficient(&self, power: usize) -> f32 {
+        return self.coefficients.get(power).copied().unwrap_or(0.0);
+    }
+
+    /// Highest power with a nonzero coefficient, 0 for the zero polynomial.
+    pub fn degree(&self) -> usize {
+        return self.coefficients.len().saturating_sub(1);
+    }
+
+    pub fn is_zero(&self) -> bool {
+        return self.coefficients.is_empty();
+    }
+
+    // Evaluation
+
+    /// Evaluates the polynomial at `x` with Horner's scheme.
+    pub fn evaluate(&self, x: f32) -> f32 {
+        return self
+            .coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |result, c| result * x + c);
+    }
+
+    /// Evaluates the polynomial at each element of `x`.
+    pub fn evaluate_matrix(&self, x: &Matrix) -> Matrix {
+        let mut result = Matrix::zeros(x.shape());
+        for m in 0..x.m {
+            for n in 0..x.n {
+                result[(m, n)] = self.evaluate(x[(m, n)]);
+            }
+        }
+        return result;
+    }
+
+    // Operations
+
+    /// Divides by `divisor` and returns the quotient and the remainder,
+    /// whose degree is lower than the degree of `divisor`.
+    pub fn div_rem(&self, divisor: &Polynomial) -> (Polynomial, Polynomial) {
+        assert!(!divisor.is_zero(), "Division by the zero polynomial");
+
+        if self.coefficients.len() < divisor.coefficients.len() {
+            return (Polynomial::zero(), self.clone());
+        }
+
+        let lead = divisor.coefficients[divisor.degree()];
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = vec![0.0; self.coefficients.len() - divisor.degree()];
+
+        for i in (0..quotient.len()).rev() {
+            let factor = remainder[i + divisor.degree()] / lead;
+            quotient[i] = factor;
+            for (j, c) in divisor.coefficients.iter().enumerate() {
+                remainder[i + j] -= factor * c;
+            }
+        }
+        remainder.truncate(divisor.degree());
+
+        return (Polynomial::from(quotient), Polynomial::from(remainder));
+    }
+
+    pub fn derivative(&self) -> Polynomial {
+        let coefficients = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(power, c)| c * power as f32)
+            .collect();
+
+        return Polynomial::from(coefficients);
+    }
+
+    /// Antiderivative with the integration constant `constant`.
+    pub fn integral(&self, constant: f32) -> Polynomial {
+        let mut coefficients = Vec::with_capacity(self.coefficients.len() + 1);
+        coefficients.push(constant);
+        for (power, c) in self.coefficients.iter().enumerate() {
+            coefficients.push(c / (power + 1) as f32);
+        }
+
+        return Polynomial::from(coefficients);
+    }
+
+    /// Definite integral from `a` to `b`.
+    pub fn integrate(&self, a: f32, b: f32) -> f32 {
+        let antiderivative = self.integral(0.0);
+        return antiderivative.evaluate(b) - antiderivative.evaluate(a);
+    }
+
+    /// Returns the companion matrix, whose characteristic polynomial is the
+    /// monic version of this polynomial.
+    pub fn companion_matrix(&self) -> Matrix {
+        assert!(self.degree() > 0, "Polynomial must at least be linear");
+
+        let n = self.degree();
+        let lead = self.coefficients[n];
+        let mut result = Matrix::zeros((n, n));
+
+        for i in 0..n {
+            result[(0, i)] = -self.coefficients[n - 1 - i] / lead;
+        }
+        for i in 1..n {
+            result[(i, i - 1)] = 1.0;
+        }
+
+        return result;
+    }
+
+    /// Returns all complex roots as `(real, imaginary)` pairs from the
+    /// eigenvalues of the companion matrix, or `None` if the eigenvalue
+    /// iteration doesn't converge.
+    pub fn roots(&self) -> Option<Vec<(f32, f32)>> {
+        assert!(!self.is_zero(), "Zero polynomial has infinitely many roots");
+
+        if self.degree() == 0 {
+            return Some(Vec::new());
+        }
+        return self.companion_matrix().eigenvalues();
+    }
+}
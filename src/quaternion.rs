@@ -0,0 +1,338 @@
+use core::{fmt, ops};
+use libm::{acosf, asinf, atan2f, sinf, sqrtf};
+
+use crate::angle::Angle;
+use crate::matrix::Matrix;
+use crate::trig::{DefaultTrig, TrigBackend};
+
+/// Unit quaternions represent rotations; the rotation conventions follow
+/// `Matrix::rotation_3d`, i.e. `R = Rz(yaw) * Ry(pitch) * Rx(roll)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion {
+    pub w: f32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl fmt::Display for Quaternion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(
+            f,
+            "{:.3} + {:.3}i + {:.3}j + {:.3}k",
+            self.w, self.x, self.y, self.z
+        );
+    }
+}
+
+impl ops::Mul<Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    /// Hamilton product, i.e. `(self * rhs)` rotates by `rhs` first.
+    fn mul(self, rhs: Quaternion) -> Self::Output {
+        return Quaternion::new(
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        );
+    }
+}
+
+impl ops::MulAssign<Quaternion> for Quaternion {
+    fn mul_assign(&mut self, rhs: Quaternion) {
+        *self = *self * rhs;
+    }
+}
+
+impl ops::Mul<f32> for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        return Quaternion::new(self.w * rhs, self.x * rhs, self.y * rhs, self.z * rhs);
+    }
+}
+
+impl ops::Add<Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    fn add(self, rhs: Quaternion) -> Self::Output {
+        return Quaternion::new(
+            self.w + rhs.w,
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+        );
+    }
+}
+
+impl ops::Neg for Quaternion {
+    type Output = Quaternion;
+
+    fn neg(self) -> Self::Output {
+        return Quaternion::new(-self.w, -self.x, -self.y, -self.z);
+    }
+}
+
+impl Quaternion {
+    // Constructors
+
+    pub const fn new(w: f32, x: f32, y: f32, z: f32) -> Self {
+        return Self { w, x, y, z };
+    }
+
+    pub const fn identity() -> Self {
+        return Self::new(1.0, 0.0, 0.0, 0.0);
+    }
+
+    /// Rotation by `angle` in any unit around the (not necessarily
+    /// normalized) axis.
+    pub fn from_axis_angle<A: Angle>(axis: [f32; 3], angle: A) -> Self {
+        return Self::from_axis_half_angle(axis, angle.half().sin_cos());
+    }
+
+    /// `from_axis_angle` with the trigonometric functions of the backend `T`.
+    pub fn from_axis_angle_with<T: TrigBackend>(axis: [f32; 3], angle: f32) -> Self {
+        return Self::from_axis_half_angle(axis, T::sin_cos(angle / 2.0));
+    }
+
+    /// Rotation around the axis with the sine and cosine of half the angle.
+    fn from_axis_half_angle(axis: [f32; 3], (sin, cos): (f32, f32)) -> Self {
+        let norm = sqrtf(axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]);
+        if norm == 0.0 {
+            return Self::identity();
+        }
+
+        let scale = sin / norm;
+        return Self::new(cos, axis[0] * scale, axis[1] * scale, axis[2] * scale);
+    }
+
+    /// Same rotation as `Matrix::rotation_3d(yaw, pitch, roll)`, with angles
+    /// in any unit.
+    pub fn from_euler<A: Angle>(yaw: A, pitch: A, roll: A) -> Self {
+        return Self::from_euler_half_angles(
+            yaw.half().sin_cos(),
+            pitch.half().sin_cos(),
+            roll.half().sin_cos(),
+        );
+    }
+
+    /// `from_euler` with the trigonometric functions of the backend `T`.
+    pub fn from_euler_with<T: TrigBackend>(yaw: f32, pitch: f32, roll: f32) -> Self {
+        return Self::from_euler_half_angles(
+            T::sin_cos(yaw / 2.0),
+            T::sin_cos(pitch / 2.0),
+            T::sin_cos(roll / 2.0),
+        );
+    }
+
+    /// Rotation of the sines and cosines of half the Euler angles.
+    fn from_euler_half_angles(
+        (sin_yaw, cos_yaw): (f32, f32),
+        (sin_pitch, cos_pitch): (f32, f32),
+        (sin_roll, cos_roll): (f32, f32),
+    ) -> Self {
+        return Self::new(
+            cos_roll * cos_pitch * cos_yaw + sin_roll * sin_pitch * sin_yaw,
+            sin_roll * cos_pitch * cos_yaw - cos_roll * sin_pitch * sin_yaw,
+            cos_roll * sin_pitch * cos_yaw + sin_roll * cos_pitch * sin_yaw,
+            cos_roll * cos_pitch * sin_yaw - sin_roll * sin_pitch * cos_yaw,
+        );
+    }
+
+    /// Converts a 3x3 rotation matrix with Shepperd's method, which stays
+    /// accurate for all rotation angles.
+    pub fn from_rotation_matrix(matrix: &Matrix) -> Self {
+        assert!(
+            matrix.shape() == (3, 3),
+            "Rotation matrix must be of shape 3x3"
+        );
+
+        let r = |i: usize, j: usize| matrix[(i, j)];
+        let trace = r(0, 0) + r(1, 1) + r(2, 2);
+
+        // Pick the largest component as divisor to avoid cancellation
+        let result = if trace > r(0, 0) && trace > r(1, 1) && trace > r(2, 2) {
+            let s = 2.0 * sqrtf(1.0 + trace);
+            Self::new(
+                s / 4.0,
+                (r(2, 1) - r(1, 2)) / s,
+                (r(0, 2) - r(2, 0)) / s,
+                (r(1, 0) - r(0, 1)) / s,
+            )
+        } else if r(0, 0) > r(1, 1) && r(0, 0) > r(2, 2) {
+            let s = 2.0 * sqrtf(1.0 + r(0, 0) - r(1, 1) - r(2, 2));
+            Self::new(
+                (r(2, 1) - r(1, 2)) / s,
+                s / 4.0,
+                (r(0, 1) + r(1, 0)) / s,
+                (r(0, 2) + r(2, 0)) / s,
+            )
+        } else if r(1, 1) > r(2, 2) {
+            let s = 2.0 * sqrtf(1.0 + r(1, 1) - r(0, 0) - r(2, 2));
+            Self::new(
+                (r(0, 2) - r(2, 0)) / s,
+                (r(0, 1) + r(1, 0)) / s,
+                s / 4.0,
+                (r(1, 2) + r(2, 1)) / s,
+            )
+        } else {
+            let s = 2.0 * sqrtf(1.0 + r(2, 2) - r(0, 0) - r(1, 1));
+            Self::new(
+                (r(1, 0) - r(0, 1)) / s,
+                (r(0, 2) + r(2, 0)) / s,
+                (r(1, 2) + r(2, 1)) / s,
+                s / 4.0,
+            )
+        };
+
+        return result.normalized();
+    }
+
+    // Conversions
+
+    /// Returns the 3x3 rotation matrix, compatible with `Matrix::rotation_3d`.
+    pub fn to_rotation_matrix(self) -> Matrix {
+        let Self { w, x, y, z } = self;
+
+        return Matrix::new([
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - w * z),
+                2.0 * (x * z + w * y),
+            ],
+            [
+                2.0 * (x * y + w * z),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - w * x),
+            ],
+            [
+                2.0 * (x * z - w * y),
+                2.0 * (y * z + w * x),
+                1.0 - 2.0 * (x * x + y * y),
+            ],
+        ]);
+    }
+
+    /// Returns `(yaw, pitch, roll)` as used by `Matrix::rotation_3d`. At
+    /// gimbal lock (pitch = ±π/2) the roll is reported as 0.
+    pub fn to_euler(self) -> (f32, f32, f32) {
+        let Self { w, x, y, z } = self;
+
+        let sin_pitch = (2.0 * (w * y - x * z)).clamp(-1.0, 1.0);
+        let pitch = asinf(sin_pitch);
+
+        if sin_pitch.abs() > 0.99999 {
+            // Only yaw - roll is observable, so fold everything into the yaw
+            let yaw = atan2f(2.0 * (w * z - x * y), 1.0 - 2.0 * (x * x + z * z));
+            return (yaw, pitch, 0.0);
+        }
+
+        let yaw = atan2f(2.0 * (w * z + x * y), 1.0 - 2.0 * (y * y + z * z));
+        let roll = atan2f(2.0 * (w * x + y * z), 1.0 - 2.0 * (x * x + y * y));
+        return (yaw, pitch, roll);
+    }
+
+    // Operations
+
+    pub fn conjugate(&self) -> Quaternion {
+        return Quaternion::new(self.w, -self.x, -self.y, -self.z);
+    }
+
+    pub fn dot(&self, rhs: &Quaternion) -> f32 {
+        return self.w * rhs.w + self.x * rhs.x + self.y * rhs.y + self.z * rhs.z;
+    }
+
+    pub fn norm(&self) -> f32 {
+        return sqrtf(self.dot(self));
+    }
+
+    /// Normalizes the quaternion inplace
+    pub fn normalize(&mut self) {
+        let norm = self.norm();
+        if norm == 0.0 {
+            *self = Self::identity();
+            return;
+        }
+        *self = *self * (1.0 / norm);
+    }
+
+    /// Returns the normalized quaternion
+    pub fn normalized(&self) -> Quaternion {
+        let mut result = *self;
+        result.normalize();
+
+        return result;
+    }
+
+    /// Rotates a column vector of length 3.
+    pub fn rotate_vector(&self, vector: &Matrix) -> Matrix {
+        assert!(
+            vector.is_column_vector() && vector.len() == 3,
+            "Provided matrix isn't a column vector of length 3"
+        );
+
+        let v = Quaternion::new(0.0, vector[(0, 0)], vector[(1, 0)], vector[(2, 0)]);
+        let rotated = *self * v * self.conjugate();
+
+        return Matrix::new([[rotated.x], [rotated.y], [rotated.z]]);
+    }
+
+    /// Normalized linear interpolation along the shorter arc. Cheaper than
+    /// `slerp`, but doesn't keep a constant angular velocity.
+    pub fn nlerp(&self, other: &Quaternion, t: f32) -> Quaternion {
+        let other = if self.dot(other) < 0.0 {
+            -*other
+        } else {
+            *other
+        };
+
+        return (*self * (1.0 - t) + other * t).normalized();
+    }
+
+    /// Spherical linear interpolation along the shorter arc.
+    pub fn slerp(&self, other: &Quaternion, t: f32) -> Quaternion {
+        let mut cos_theta = self.dot(other);
+        let other = if cos_theta < 0.0 {
+            cos_theta = -cos_theta;
+            -*other
+        } else {
+            *other
+        };
+
+        // Fall back to nlerp when the angle is too small for a stable division
+        if cos_theta > 0.9995 {
+            return self.nlerp(&other, t);
+        }
+
+        let theta = acosf(cos_theta);
+        let sin_theta = sinf(theta);
+        let a = sinf((1.0 - t) * theta) / sin_theta;
+        let b = sinf(t * theta) / sin_theta;
+
+        return (*self * a + other * b).normalized();
+    }
+
+    /// Integrates body-frame angular rates (rad/s) over `dt` seconds,
+    /// assuming the rates are constant during the step.
+    pub fn integrate(&mut self, rates: [f32; 3], dt: f32) {
+        let rate = sqrtf(rates[0] * rates[0] + rates[1] * rates[1] + rates[2] * rates[2]);
+        let angle = rate * dt;
+
+        // Use the exact exponential map, which keeps the norm for large steps
+        let delta = if angle > 1e-6 {
+            let (sin, cos) = DefaultTrig::sin_cos(angle / 2.0);
+            let scale = sin / rate;
+            Quaternion::new(cos, rates[0] * scale, rates[1] * scale, rates[2] * scale)
+        } else {
+            Quaternion::new(
+                1.0,
+                rates[0] * dt / 2.0,
+                rates[1] * dt / 2.0,
+                rates[2] * dt / 2.0,
+            )
+        };
+
+        *self = (*self * delta).normalized();
+    }
+}
@@ -0,0 +1,171 @@
+use core::marker::PhantomData;
+
+use libm::{atan2f, floorf, sincosf};
+
+use crate::asm_math;
+
+/// Source of the trigonometric functions of the rotation constructors and
+/// the attitude filters.
+///
+/// Backends are zero-sized types passed as generic parameter, e.g.
+/// `Matrix::rotation_2d_with::<Cordic>(angle)`. Functions without parameter
+/// use `DefaultTrig`, which the cargo features `trig-cordic` and `trig-lut`
+/// switch from libm to the respective backend.
+pub trait TrigBackend {
+    /// Returns `(sin(angle), cos(angle))`.
+    fn sin_cos(angle: f32) -> (f32, f32);
+
+    fn atan2(y: f32, x: f32) -> f32;
+
+    fn sin(angle: f32) -> f32 {
+        return Self::sin_cos(angle).0;
+    }
+
+    fn cos(angle: f32) -> f32 {
+        return Self::sin_cos(angle).1;
+    }
+}
+
+#[cfg(all(feature = "trig-cordic", feature = "trig-lut"))]
+compile_error!("The features `trig-cordic` and `trig-lut` are mutually exclusive");
+
+#[cfg(feature = "trig-cordic")]
+pub type DefaultTrig = Cordic;
+#[cfg(all(feature = "trig-lut", not(feature = "trig-cordic")))]
+pub type DefaultTrig = LookupTable;
+#[cfg(not(any(feature = "trig-cordic", feature = "trig-lut")))]
+pub type DefaultTrig = Libm;
+
+/// Correctly rounded functions of the libm crate.
+#[derive(Debug, Clone, Copy)]
+pub struct Libm;
+
+impl TrigBackend for Libm {
+    fn sin_cos(angle: f32) -> (f32, f32) {
+        return sincosf(angle);
+    }
+
+    fn atan2(y: f32, x: f32) -> f32 {
+        return atan2f(y, x);
+    }
+}
+
+/// CORDIC of `asm_math` with `N` iterations, accurate to about `2^-N`.
+#[derive(Debug, Clone, Copy)]
+pub struct Cordic<const N: usize = { asm_math::MAX_ITERATIONS }>;
+
+impl<const N: usize> TrigBackend for Cordic<N> {
+    fn sin_cos(angle: f32) -> (f32, f32) {
+        return asm_math::sincos_n::<N>(angle);
+    }
+
+    fn atan2(y: f32, x: f32) -> f32 {
+        return asm_math::atan2_hypot_n::<N>(y, x).0;
+    }
+}
+
+// Tables generated by build.rs, sized by `TRIG_SINE_SEGMENTS` and
+// `TRIG_ATAN_SEGMENTS`
+include!(concat!(env!("OUT_DIR"), "/trig_tables.rs"));
+
+/// Flash taken by the tables of `LookupTable`, in bytes.
+pub const LOOKUP_TABLE_BYTES: usize =
+    core::mem::size_of_val(&SINE_TABLE) + core::mem::size_of_val(&ATAN_TABLE);
+
+/// `PI / 2` split into a part with trailing zero bits, so `k * FRAC_PI_2_HI`
+/// is exact for `|k| < 256`, and the remainder
+const FRAC_PI_2_HI: f32 = f32::from_bits(0x3fc9_0f00);
+const FRAC_PI_2_LO: f32 = f32::from_bits(0x37da_a221);
+
+/// Interpolation between the entries of the tables of `LookupTable`.
+pub trait TableInterpolation {
+    /// Interpolates `table` at `position` in `[0, table.len() - 1]`.
+    fn interpolate(table: &[f32], position: f32) -> f32;
+}
+
+/// Linear interpolation between neighbouring entries, with an error of
+/// about `h^2 / 8` times the second derivative for the spacing `h`.
+#[derive(Debug, Clone, Copy)]
+pub struct Linear;
+
+impl TableInterpolation for Linear {
+    fn interpolate(table: &[f32], position: f32) -> f32 {
+        let i = (position as usize).min(table.len() - 2);
+        let t = position - i as f32;
+        return table[i] + t * (table[i + 1] - table[i]);
+    }
+}
+
+/// Quadratic interpolation through three neighbouring entries, with an
+/// error of about `h^3 / 16` times the third derivative for the spacing
+/// `h`.
+#[derive(Debug, Clone, Copy)]
+pub struct Quadratic;
+
+impl TableInterpolation for Quadratic {
+    fn interpolate(table: &[f32], position: f32) -> f32 {
+        let i = (position as usize).min(table.len() - 3);
+        let t = position - i as f32;
+
+        // Newton form with forward differences
+        let first = table[i + 1] - table[i];
+        let second = table[i + 2] - 2.0 * table[i + 1] + table[i];
+        return table[i] + t * (first + 0.5 * (t - 1.0) * second);
+    }
+}
+
+/// Lookup tables of a quarter sine wave and of the arctangent on `[0, 1]`,
+/// generated by build.rs and interpolated by `I`.
+///
+/// The tables take `LOOKUP_TABLE_BYTES` of flash. With the default 256
+/// segments the error is below `5e-6` for the sine and `2e-6` for the
+/// arctangent with `Linear` interpolation, and below `3e-7` for both with
+/// `Quadratic` interpolation. Evaluation takes a fixed number of operations.
+#[derive(Debug, Clone, Copy)]
+pub struct LookupTable<I: TableInterpolation = Linear>(PhantomData<I>);
+
+impl<I: TableInterpolation> TrigBackend for LookupTable<I> {
+    fn sin_cos(angle: f32) -> (f32, f32) {
+        if !angle.is_finite() {
+            return (f32::NAN, f32::NAN);
+        }
+
+        // Quadrant and position in it, subtracting the quadrants in two
+        // parts to keep the remainder exact for moderately large angles
+        let floor = floorf(angle * core::f32::consts::FRAC_2_PI);
+        let quadrant = (floor as i64 & 3) as u8;
+        let remainder = angle - floor * FRAC_PI_2_HI - floor * FRAC_PI_2_LO;
+        let position = remainder * core::f32::consts::FRAC_2_PI * SINE_SEGMENTS as f32;
+
+        let rising = I::interpolate(&SINE_TABLE, position);
+        let falling = I::interpolate(&SINE_TABLE, SINE_SEGMENTS as f32 - position);
+
+        return match quadrant {
+            0 => (rising, falling),
+            1 => (falling, -rising),
+            2 => (-rising, -falling),
+            _ => (-falling, rising),
+        };
+    }
+
+    fn atan2(y: f32, x: f32) -> f32 {
+        if x.is_nan() || y.is_nan() {
+            return f32::NAN;
+        }
+        let (abs_x, abs_y) = (x.abs(), y.abs());
+        if abs_x == 0.0 && abs_y == 0.0 {
+            return 0.0;
+        }
+
+        // Reduce to the first octant, where the ratio is in [0, 1]
+        let ratio = f32::min(abs_x, abs_y) / f32::max(abs_x, abs_y);
+        let mut angle = I::interpolate(&ATAN_TABLE, ratio * ATAN_SEGMENTS as f32);
+        if abs_y > abs_x {
+            angle = core::f32::consts::FRAC_PI_2 - angle;
+        }
+        if x < 0.0 {
+            angle = core::f32::consts::PI - angle;
+        }
+        return if y < 0.0 { -angle } else { angle };
+    }
+}